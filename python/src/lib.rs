@@ -25,6 +25,22 @@ struct MinotaurInput {
     mass_tol: c_double,
     energy_tol: c_double,
     t4_max: c_double,
+    // Armijo–Goldstein line search (v0.7): 0=fixed damping (default, unchanged),
+    // 1=backtracking Armijo globalization of the Newton update.
+    linesearch: c_int,
+    ls_c: c_double,       // Armijo sufficient-decrease constant c1 (~1e-4)
+    ls_rho: c_double,     // Backtracking contraction factor (~0.5)
+    ls_max_iter: c_int,   // Maximum backtracks per Newton step
+    // DIIS/Anderson acceleration (v0.8): 0=plain fixed-point (default),
+    // 1=DIIS extrapolation over a rolling residual history.
+    accel: c_int,
+    diis_size: c_int,     // Residual-history depth m for DIIS (ignored when accel=0)
+    // User-supplied initial-state seed (v0.9): when has_guess is 1 the core
+    // starts the iteration from these values instead of its internal heuristic.
+    has_guess: c_int,
+    t4_guess: c_double,   // Turbine-inlet temperature seed [K]
+    bpr_guess: c_double,  // Bypass-ratio seed
+    fuel_guess: c_double, // Fuel-flow seed
 }
 
 #[repr(C)]
@@ -39,6 +55,9 @@ struct MinotaurOutput {
     thrust_proxy: c_double,
     final_bpr: c_double,
     final_residual: c_double,
+    // Total number of line-search backtracks over the solve (v0.7); 0 when the
+    // line search is disabled.
+    line_search_backtracks: c_int,
 }
 
 // Extended input for component models and degradation
@@ -74,11 +93,105 @@ struct MinotaurInputExt {
     eta_turb_factor: c_double,
     loss_adder: c_double,
     is_degraded: c_int,
+    // Armijo–Goldstein line search (v0.7): see MinotaurInput.
+    linesearch: c_int,
+    ls_c: c_double,
+    ls_rho: c_double,
+    ls_max_iter: c_int,
+    // DIIS/Anderson acceleration (v0.8): see MinotaurInput.
+    accel: c_int,
+    diis_size: c_int,
+    // User-supplied initial-state seed (v0.9): see MinotaurInput.
+    has_guess: c_int,
+    t4_guess: c_double,
+    bpr_guess: c_double,
+    fuel_guess: c_double,
+    // Mean-line radial-compressor model (v0.10): active when
+    // compressor_model=2. `eta_comp` above is then the model's derived
+    // efficiency rather than a user-set constant; see `meanline_efficiency`.
+    ml_phi1: c_double,        // Inlet flow coefficient
+    ml_mach1_rel: c_double,   // Inlet relative Mach number
+    ml_hub_tip: c_double,     // Hub-to-tip radius ratio
+    ml_dh_impeller: c_double, // Impeller de Haller number (exit/inlet velocity)
+    ml_dh_diffuser: c_double, // Vaneless-diffuser de Haller number
+    ml_loss_split: c_double,  // Fraction of the entropy-rise budget charged to the impeller
 }
 
+/// Compressor-model selector for a mean-line radial-compressor stage (v0.10).
+const COMPRESSOR_MODEL_MEANLINE: c_int = 2;
+
 extern "C" {
     fn minotaur_solve_c(inp: MinotaurInput, out: *mut MinotaurOutput);
     fn minotaur_solve_ext_c(inp_ext: MinotaurInputExt, out: *mut MinotaurOutput);
+
+    // Complex-step differentiation (v0.11): each of the six base parameters
+    // takes a (real, imaginary) pair, and the residual/output evaluation
+    // propagates complex arithmetic through so dF/dx = Im(F(x+ih))/h is exact
+    // to machine precision for a tiny h, free of the subtractive-cancellation
+    // error that limits `sensitivity`'s central-difference mode.
+    fn minotaur_solve_cs_c(
+        mach_re: c_double, mach_im: c_double,
+        alt_km_re: c_double, alt_km_im: c_double,
+        bpr_re: c_double, bpr_im: c_double,
+        opr_re: c_double, opr_im: c_double,
+        eta_comp_re: c_double, eta_comp_im: c_double,
+        eta_turb_re: c_double, eta_turb_im: c_double,
+        eta_nozz: c_double,
+        fuel_k: c_double,
+        t4_max: c_double,
+        tsfc_re: *mut c_double, tsfc_im: *mut c_double,
+        thrust_re: *mut c_double, thrust_im: *mut c_double,
+        t4_re: *mut c_double, t4_im: *mut c_double,
+        status: *mut c_int,
+    );
+}
+
+/// One complex-step solve with parameter `seed` perturbed by `h` in the
+/// imaginary direction; returns `(d_tsfc, d_thrust, d_t4, status)` (v0.11).
+/// `seed` indexes `[mach, alt_km, bpr, opr, eta_comp, eta_turb]`.
+fn solve_complex_step(
+    mach: f64,
+    alt_km: f64,
+    bpr: f64,
+    opr: f64,
+    eta_comp: f64,
+    eta_turb: f64,
+    eta_nozz: f64,
+    fuel_k: f64,
+    t4_max: f64,
+    seed: usize,
+    h: f64,
+) -> (f64, f64, f64, i32) {
+    let mut im = [0.0f64; 6];
+    im[seed] = h;
+
+    let mut tsfc_re: c_double = 0.0;
+    let mut tsfc_im: c_double = 0.0;
+    let mut thrust_re: c_double = 0.0;
+    let mut thrust_im: c_double = 0.0;
+    let mut t4_re: c_double = 0.0;
+    let mut t4_im: c_double = 0.0;
+    let mut status: c_int = 0;
+
+    unsafe {
+        minotaur_solve_cs_c(
+            mach, im[0],
+            alt_km, im[1],
+            bpr, im[2],
+            opr, im[3],
+            eta_comp, im[4],
+            eta_turb, im[5],
+            eta_nozz,
+            fuel_k,
+            t4_max,
+            &mut tsfc_re, &mut tsfc_im,
+            &mut thrust_re, &mut thrust_im,
+            &mut t4_re, &mut t4_im,
+            &mut status,
+        );
+    }
+
+    (tsfc_im / h, thrust_im / h, t4_im / h, status)
 }
 
 fn solve_internal(inp: MinotaurInput) -> MinotaurOutput {
@@ -92,6 +205,7 @@ fn solve_internal(inp: MinotaurInput) -> MinotaurOutput {
         thrust_proxy: 0.0,
         final_bpr: 0.0,
         final_residual: 0.0,
+        line_search_backtracks: 0,
     };
     unsafe { minotaur_solve_c(inp, &mut out as *mut _) };
     out
@@ -108,6 +222,7 @@ fn solve_ext_internal(inp_ext: MinotaurInputExt) -> MinotaurOutput {
         thrust_proxy: 0.0,
         final_bpr: 0.0,
         final_residual: 0.0,
+        line_search_backtracks: 0,
     };
     unsafe { minotaur_solve_ext_c(inp_ext, &mut out as *mut _) };
     out
@@ -139,6 +254,8 @@ struct SolverResult {
     final_bpr: f64,
     #[pyo3(get)]
     final_residual: f64,
+    #[pyo3(get)]
+    line_search_backtracks: i32,
 }
 
 #[pymethods]
@@ -163,6 +280,7 @@ impl SolverResult {
         dict.set_item("thrust_proxy", self.thrust_proxy)?;
         dict.set_item("final_bpr", self.final_bpr)?;
         dict.set_item("final_residual", self.final_residual)?;
+        dict.set_item("line_search_backtracks", self.line_search_backtracks)?;
         Ok(dict.into())
     }
 }
@@ -192,6 +310,64 @@ fn output_to_result(out: &MinotaurOutput) -> SolverResult {
         thrust_proxy: out.thrust_proxy,
         final_bpr: out.final_bpr,
         final_residual: out.final_residual,
+        line_search_backtracks: out.line_search_backtracks,
+    }
+}
+
+/// Map a `linesearch=` keyword to the core's line-search mode selector,
+/// rejecting unknown modes with a Python `ValueError` (v0.7).
+fn linesearch_mode(name: &str) -> PyResult<c_int> {
+    match name {
+        "none" => Ok(0),
+        "armijo" => Ok(1),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(
+            "linesearch must be 'none' or 'armijo'",
+        )),
+    }
+}
+
+/// Map an `accel=` keyword to the core's acceleration-mode selector and
+/// validate the companion history depth (v0.8). DIIS needs at least a
+/// two-entry history to extrapolate.
+fn accel_mode(name: &str, diis_size: i32) -> PyResult<c_int> {
+    match name {
+        "none" => Ok(0),
+        "diis" => {
+            if diis_size < 2 {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "diis_size must be >= 2 for accel='diis'",
+                ));
+            }
+            Ok(1)
+        }
+        _ => Err(pyo3::exceptions::PyValueError::new_err(
+            "accel must be 'none' or 'diis'",
+        )),
+    }
+}
+
+/// Resolve the optional `t4_guess`/`bpr_guess`/`fuel_guess` keywords into the
+/// `(has_guess, t4, bpr, fuel)` tuple expected by the core (v0.9). Seeding is
+/// all-or-nothing: either every component is supplied or none is, so a partial
+/// guess can't silently mix a user value with an internal default.
+fn resolve_guess(
+    t4_guess: Option<f64>,
+    bpr_guess: Option<f64>,
+    fuel_guess: Option<f64>,
+) -> PyResult<(c_int, f64, f64, f64)> {
+    match (t4_guess, bpr_guess, fuel_guess) {
+        (None, None, None) => Ok((0, 0.0, 0.0, 0.0)),
+        (Some(t4), Some(bpr), Some(fuel)) => {
+            if t4 <= 0.0 || bpr <= 0.0 || fuel <= 0.0 {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "t4_guess, bpr_guess, and fuel_guess must be positive",
+                ));
+            }
+            Ok((1, t4, bpr, fuel))
+        }
+        _ => Err(pyo3::exceptions::PyValueError::new_err(
+            "t4_guess, bpr_guess, and fuel_guess must be given together or not at all",
+        )),
     }
 }
 
@@ -211,7 +387,16 @@ fn output_to_result(out: &MinotaurOutput) -> SolverResult {
     damping = 0.5,
     mass_tol = 1e-9,
     energy_tol = 1e-9,
-    t4_max = 1400.0
+    t4_max = 1400.0,
+    linesearch = "none",
+    c = 1e-4,
+    rho = 0.5,
+    max_ls_iter = 20,
+    accel = "none",
+    diis_size = 5,
+    t4_guess = None,
+    bpr_guess = None,
+    fuel_guess = None
 ))]
 fn solve(
     mach: f64,
@@ -228,7 +413,17 @@ fn solve(
     mass_tol: f64,
     energy_tol: f64,
     t4_max: f64,
+    linesearch: &str,
+    c: f64,
+    rho: f64,
+    max_ls_iter: i32,
+    accel: &str,
+    diis_size: i32,
+    t4_guess: Option<f64>,
+    bpr_guess: Option<f64>,
+    fuel_guess: Option<f64>,
 ) -> PyResult<SolverResult> {
+    let (has_guess, t4_g, bpr_g, fuel_g) = resolve_guess(t4_guess, bpr_guess, fuel_guess)?;
     let inp = MinotaurInput {
         mach,
         alt_km,
@@ -244,6 +439,16 @@ fn solve(
         mass_tol,
         energy_tol,
         t4_max,
+        linesearch: linesearch_mode(linesearch)?,
+        ls_c: c,
+        ls_rho: rho,
+        ls_max_iter: max_ls_iter,
+        accel: accel_mode(accel, diis_size)?,
+        diis_size,
+        has_guess,
+        t4_guess: t4_g,
+        bpr_guess: bpr_g,
+        fuel_guess: fuel_g,
     };
 
     let out = solve_internal(inp);
@@ -276,7 +481,16 @@ fn solve(
     nozzle_loss = 0.01,
     eta_comp_factor = 1.0,
     eta_turb_factor = 1.0,
-    loss_adder = 0.0
+    loss_adder = 0.0,
+    linesearch = "none",
+    c = 1e-4,
+    rho = 0.5,
+    max_ls_iter = 20,
+    accel = "none",
+    diis_size = 5,
+    t4_guess = None,
+    bpr_guess = None,
+    fuel_guess = None
 ))]
 fn solve_extended(
     mach: f64,
@@ -303,7 +517,17 @@ fn solve_extended(
     eta_comp_factor: f64,
     eta_turb_factor: f64,
     loss_adder: f64,
+    linesearch: &str,
+    c: f64,
+    rho: f64,
+    max_ls_iter: i32,
+    accel: &str,
+    diis_size: i32,
+    t4_guess: Option<f64>,
+    bpr_guess: Option<f64>,
+    fuel_guess: Option<f64>,
 ) -> PyResult<SolverResult> {
+    let (has_guess, t4_g, bpr_g, fuel_g) = resolve_guess(t4_guess, bpr_guess, fuel_guess)?;
     let is_degraded = if eta_comp_factor < 1.0 || eta_turb_factor < 1.0 || loss_adder > 0.0 {
         1
     } else {
@@ -336,6 +560,180 @@ fn solve_extended(
         eta_turb_factor,
         loss_adder,
         is_degraded,
+        linesearch: linesearch_mode(linesearch)?,
+        ls_c: c,
+        ls_rho: rho,
+        ls_max_iter: max_ls_iter,
+        accel: accel_mode(accel, diis_size)?,
+        diis_size,
+        has_guess,
+        t4_guess: t4_g,
+        bpr_guess: bpr_g,
+        fuel_guess: fuel_g,
+        // No mean-line geometry unless the caller goes through
+        // `solve_meanline_compressor`, which builds its own `MinotaurInputExt`.
+        ml_phi1: 0.0,
+        ml_mach1_rel: 0.0,
+        ml_hub_tip: 0.0,
+        ml_dh_impeller: 0.0,
+        ml_dh_diffuser: 0.0,
+        ml_loss_split: 0.0,
+    };
+
+    let out = solve_ext_internal(inp_ext);
+    Ok(output_to_result(&out))
+}
+
+/// Achieved total-to-total isentropic efficiency of a mean-line radial
+/// compressor stage, derived from geometry and aerodynamic loading rather
+/// than taken as a flat constant (v0.10).
+///
+/// The impeller and vaneless diffuser are each modelled as a diffusing
+/// passage whose loss grows sharply as its de Haller number (exit/inlet
+/// velocity ratio) falls below the ~0.72 diffusion-stall limit; an
+/// incidence/secondary-flow penalty from the inlet flow coefficient and
+/// relative Mach number is added on top, with a thinner hub-to-tip ratio
+/// leaving less annulus area to recover it. The combined entropy-rise
+/// loss coefficient, split between impeller and diffuser by
+/// `loss_split_impeller`, is converted to an efficiency through the usual
+/// `eta_tt = 1 / (1 + zeta / (pr_tt^((gamma-1)/gamma) - 1))` relation
+/// between entropy-rise loss and ideal work at the stage's stagnation
+/// pressure ratio.
+fn meanline_efficiency(
+    pr_tt: f64,
+    phi1: f64,
+    mach1_rel: f64,
+    hub_tip_ratio: f64,
+    dh_impeller: f64,
+    dh_diffuser: f64,
+    loss_split_impeller: f64,
+) -> f64 {
+    const GAMMA: f64 = 1.4;
+
+    let diffusion_loss = |dh: f64| -> f64 {
+        let dh = dh.clamp(0.3, 1.0);
+        0.05 * ((1.0 - dh) / dh).powi(2)
+    };
+
+    let zeta_impeller = diffusion_loss(dh_impeller);
+    let zeta_diffuser = diffusion_loss(dh_diffuser);
+    let incidence_loss = 0.02 * phi1 * phi1
+        + 0.03 * mach1_rel.max(0.0).powi(2) * (1.0 - hub_tip_ratio).max(0.0);
+
+    let split = loss_split_impeller.clamp(0.0, 1.0);
+    let zeta_total = zeta_impeller * split + zeta_diffuser * (1.0 - split) + incidence_loss;
+
+    let exp = (GAMMA - 1.0) / GAMMA;
+    let ideal_temp_ratio = pr_tt.max(1.0001).powf(exp) - 1.0;
+    (1.0 / (1.0 + zeta_total / ideal_temp_ratio)).clamp(0.3, 0.95)
+}
+
+/// Solve a cycle point with the mean-line radial-compressor model, deriving
+/// `eta_comp` from geometry/loading instead of taking it as an input (v0.10).
+///
+/// Lets users study how loading choices (de Haller numbers, loss split)
+/// propagate to TSFC and thrust by sweeping the geometry keywords directly.
+#[pyfunction]
+#[pyo3(signature = (
+    mach,
+    alt_km,
+    bpr,
+    opr,
+    phi1 = 0.55,
+    mach1_rel = 0.9,
+    hub_tip_ratio = 0.3,
+    dh_impeller = 0.75,
+    dh_diffuser = 0.8,
+    loss_split_impeller = 0.6,
+    eta_turb = 0.86,
+    eta_nozz = 0.95,
+    fuel_k = 1.0,
+    max_iter = 200,
+    tol = 1e-10,
+    damping = 0.5,
+    mass_tol = 1e-9,
+    energy_tol = 1e-9,
+    t4_max = 1400.0
+))]
+fn solve_meanline_compressor(
+    mach: f64,
+    alt_km: f64,
+    bpr: f64,
+    opr: f64,
+    phi1: f64,
+    mach1_rel: f64,
+    hub_tip_ratio: f64,
+    dh_impeller: f64,
+    dh_diffuser: f64,
+    loss_split_impeller: f64,
+    eta_turb: f64,
+    eta_nozz: f64,
+    fuel_k: f64,
+    max_iter: i32,
+    tol: f64,
+    damping: f64,
+    mass_tol: f64,
+    energy_tol: f64,
+    t4_max: f64,
+) -> PyResult<SolverResult> {
+    if !(0.0..=1.0).contains(&loss_split_impeller) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "loss_split_impeller must lie in [0, 1]",
+        ));
+    }
+
+    let eta_comp = meanline_efficiency(
+        opr,
+        phi1,
+        mach1_rel,
+        hub_tip_ratio,
+        dh_impeller,
+        dh_diffuser,
+        loss_split_impeller,
+    );
+
+    let inp_ext = MinotaurInputExt {
+        mach,
+        alt_km,
+        bpr,
+        opr,
+        eta_comp,
+        eta_turb,
+        eta_nozz,
+        fuel_k,
+        max_iter,
+        tol,
+        damping,
+        mass_tol,
+        energy_tol,
+        t4_max,
+        compressor_model: COMPRESSOR_MODEL_MEANLINE,
+        turbine_model: 0,
+        nozzle_model: 0,
+        inlet_loss: 0.02,
+        burner_loss: 0.04,
+        turbine_mech_loss: 0.02,
+        nozzle_loss: 0.01,
+        eta_comp_factor: 1.0,
+        eta_turb_factor: 1.0,
+        loss_adder: 0.0,
+        is_degraded: 0,
+        linesearch: 0,
+        ls_c: 1e-4,
+        ls_rho: 0.5,
+        ls_max_iter: 20,
+        accel: 0,
+        diis_size: 5,
+        has_guess: 0,
+        t4_guess: 0.0,
+        bpr_guess: 0.0,
+        fuel_guess: 0.0,
+        ml_phi1: phi1,
+        ml_mach1_rel: mach1_rel,
+        ml_hub_tip: hub_tip_ratio,
+        ml_dh_impeller: dh_impeller,
+        ml_dh_diffuser: dh_diffuser,
+        ml_loss_split: loss_split_impeller,
     };
 
     let out = solve_ext_internal(inp_ext);
@@ -343,7 +741,22 @@ fn solve_extended(
 }
 
 /// Run a parameter sweep over BPR and OPR ranges (NumPy-compatible)
+///
+/// With `chain_guess=True` each OPR column is warm-started from the previous
+/// converged cell in the same BPR row (v0.9), which can pull otherwise divergent
+/// cells back onto a solution when marching into stiff high-OPR regions.
 #[pyfunction]
+#[pyo3(signature = (
+    bpr_values,
+    opr_values,
+    mach,
+    alt_km,
+    eta_comp,
+    eta_turb,
+    eta_nozz,
+    t4_max,
+    chain_guess = false
+))]
 fn sweep<'py>(
     py: Python<'py>,
     bpr_values: PyReadonlyArray1<'py, f64>,
@@ -354,6 +767,7 @@ fn sweep<'py>(
     eta_turb: f64,
     eta_nozz: f64,
     t4_max: f64,
+    chain_guess: bool,
 ) -> PyResult<&'py PyDict> {
     let bpr_arr = bpr_values.as_slice()?;
     let opr_arr = opr_values.as_slice()?;
@@ -371,7 +785,14 @@ fn sweep<'py>(
     let mut opr_out = Vec::with_capacity(n_total);
 
     for &bpr in bpr_arr {
+        // Seed carried along each BPR row when chaining is enabled; reset at the
+        // start of every row so an unconverged cell can't poison the next row.
+        let mut prev: Option<MinotaurOutput> = None;
         for &opr in opr_arr {
+            let (has_guess, t4_guess, bpr_guess, fuel_guess) = match (chain_guess, prev) {
+                (true, Some(p)) if p.status == 0 => (1, p.t4, p.final_bpr, 1.0),
+                _ => (0, 0.0, 0.0, 0.0),
+            };
             let inp = MinotaurInput {
                 mach,
                 alt_km,
@@ -387,9 +808,20 @@ fn sweep<'py>(
                 mass_tol: 1e-9,
                 energy_tol: 1e-9,
                 t4_max,
+                linesearch: 0,
+                ls_c: 1e-4,
+                ls_rho: 0.5,
+                ls_max_iter: 20,
+                accel: 0,
+                diis_size: 5,
+                has_guess,
+                t4_guess,
+                bpr_guess,
+                fuel_guess,
             };
 
             let out = solve_internal(inp);
+            prev = Some(out);
 
             status_vec.push(out.status);
             iter_vec.push(out.iter);
@@ -415,7 +847,15 @@ fn sweep<'py>(
     Ok(result)
 }
 
-/// Compute local sensitivities via central finite differences
+/// Compute local sensitivities via central finite differences or complex-step
+/// differentiation.
+///
+/// `method="central"` (default) perturbs each parameter by a relative `step`
+/// and trades off truncation error against subtractive-cancellation error.
+/// `method="complex_step"` instead perturbs each parameter by a tiny
+/// imaginary step `h` and reads the Jacobian off `Im(F(x+ih))/h`, which is
+/// exact to machine precision and free of cancellation (v0.11); `step` is
+/// ignored in that mode.
 #[pyfunction]
 #[pyo3(signature = (
     mach,
@@ -424,7 +864,8 @@ fn sweep<'py>(
     opr,
     eta_comp = 0.82,
     eta_turb = 0.86,
-    step = 1e-6
+    step = 1e-6,
+    method = "central"
 ))]
 fn sensitivity<'py>(
     py: Python<'py>,
@@ -435,7 +876,13 @@ fn sensitivity<'py>(
     eta_comp: f64,
     eta_turb: f64,
     step: f64,
+    method: &str,
 ) -> PyResult<&'py PyDict> {
+    if method != "central" && method != "complex_step" {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "method must be 'central' or 'complex_step'",
+        ));
+    }
     let base_inp = MinotaurInput {
         mach,
         alt_km,
@@ -451,6 +898,16 @@ fn sensitivity<'py>(
         mass_tol: 1e-9,
         energy_tol: 1e-9,
         t4_max: 1400.0,
+        linesearch: 0,
+        ls_c: 1e-4,
+        ls_rho: 0.5,
+        ls_max_iter: 20,
+        accel: 0,
+        diis_size: 5,
+        has_guess: 0,
+        t4_guess: 0.0,
+        bpr_guess: 0.0,
+        fuel_guess: 0.0,
     };
 
     let base_out = solve_internal(base_inp);
@@ -459,6 +916,43 @@ fn sensitivity<'py>(
     let params = ["bpr", "opr", "eta_comp", "eta_turb", "mach", "alt_km"];
     let mut jacobian = Vec::new();
 
+    if method == "complex_step" {
+        const H: f64 = 1e-30;
+        for param in &params {
+            // `solve_complex_step` seeds are ordered [mach, alt_km, bpr, opr,
+            // eta_comp, eta_turb], which differs from `params`' reporting order.
+            let seed = match *param {
+                "mach" => 0,
+                "alt_km" => 1,
+                "bpr" => 2,
+                "opr" => 3,
+                "eta_comp" => 4,
+                "eta_turb" => 5,
+                _ => continue,
+            };
+            let (d_tsfc, d_thrust, d_t4, _status) = solve_complex_step(
+                mach, alt_km, bpr, opr, eta_comp, eta_turb, 0.95, 1.0, 1400.0, seed, H,
+            );
+            jacobian.push(vec![d_tsfc, d_thrust, d_t4]);
+        }
+
+        let result = PyDict::new(py);
+        result.set_item("parameters", params.to_vec())?;
+        result.set_item("outputs", vec!["tsfc", "thrust", "t4"])?;
+
+        let flat: Vec<f64> = jacobian.iter().flatten().copied().collect();
+        let arr = PyArray2::from_vec(py, flat)
+            .reshape([6, 3])
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{:?}", e)))?;
+        result.set_item("jacobian", arr)?;
+
+        result.set_item("base_tsfc", base_out.tsfc_proxy)?;
+        result.set_item("base_thrust", base_out.thrust_proxy)?;
+        result.set_item("base_t4", base_out.t4)?;
+
+        return Ok(result);
+    }
+
     for param in &params {
         let (h, mut inp_plus, mut inp_minus) = match *param {
             "bpr" => {
@@ -588,6 +1082,16 @@ fn compare_degradation(
         mass_tol: 1e-9,
         energy_tol: 1e-9,
         t4_max: 1400.0,
+        linesearch: 0,
+        ls_c: 1e-4,
+        ls_rho: 0.5,
+        ls_max_iter: 20,
+        accel: 0,
+        diis_size: 5,
+        has_guess: 0,
+        t4_guess: 0.0,
+        bpr_guess: 0.0,
+        fuel_guess: 0.0,
     };
     let out_nom = solve_internal(inp_nom);
 
@@ -618,6 +1122,22 @@ fn compare_degradation(
         eta_turb_factor: kt,
         loss_adder: delta_loss,
         is_degraded: 1,
+        linesearch: 0,
+        ls_c: 1e-4,
+        ls_rho: 0.5,
+        ls_max_iter: 20,
+        accel: 0,
+        diis_size: 5,
+        has_guess: 0,
+        t4_guess: 0.0,
+        bpr_guess: 0.0,
+        fuel_guess: 0.0,
+        ml_phi1: 0.0,
+        ml_mach1_rel: 0.0,
+        ml_hub_tip: 0.0,
+        ml_dh_impeller: 0.0,
+        ml_dh_diffuser: 0.0,
+        ml_loss_split: 0.0,
     };
     let out_deg = solve_ext_internal(inp_deg);
 
@@ -656,6 +1176,7 @@ fn minotaur_python(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<SolverResult>()?;
     m.add_function(wrap_pyfunction!(solve, m)?)?;
     m.add_function(wrap_pyfunction!(solve_extended, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_meanline_compressor, m)?)?;
     m.add_function(wrap_pyfunction!(sweep, m)?)?;
     m.add_function(wrap_pyfunction!(sensitivity, m)?)?;
     m.add_function(wrap_pyfunction!(compare_degradation, m)?)?;
@@ -0,0 +1,235 @@
+//! Projected quasi-Newton (BFGS) optimizer with an exact-penalty merit.
+//!
+//! A gradient-based complement to the derivative-free [`nsga2`](crate::nsga2)
+//! optimizer and the nonlinear [`cg`](crate::cg) solver: it polishes a single
+//! scalarized objective using the analytic gradient from
+//! `ffi::compute_jacobian`, handling the `t4 <= t4_max` limit through an exact
+//! penalty folded into the merit function.
+//!
+//! The step direction is `d = −H·g`, where `H` is the maintained inverse-Hessian
+//! approximation (identity on the first iteration). A backtracking Armijo line
+//! search along `d` accepts the first step that sufficiently decreases the
+//! merit; the candidate is projected onto the box bounds before evaluation.
+//! After an accepted step the inverse Hessian is refreshed with the standard
+//! `(s, y)` BFGS update, skipping the update whenever the curvature `s·y` is
+//! non-positive so `H` stays positive definite.
+//!
+//! The value oracle (merit at a trial point, via `ffi::solve`) and the gradient
+//! oracle (via `ffi::compute_jacobian`) are passed separately so the expensive
+//! Jacobian is only formed at accepted iterates, not at every line-search trial.
+//!
+//! Author: CSTNSystems
+//! Version: 2.25.0
+
+/// Projected-BFGS configuration.
+#[derive(Clone, Debug)]
+pub struct BfgsConfig {
+    /// Variable bounds: [(min, max), ...].
+    pub bounds: Vec<(f64, f64)>,
+    /// Maximum outer iterations.
+    pub max_iter: usize,
+    /// Convergence tolerance on the gradient norm.
+    pub grad_tol: f64,
+    /// Initial line-search step length.
+    pub step_init: f64,
+    /// Backtracking contraction factor in (0, 1).
+    pub step_contract: f64,
+    /// Armijo sufficient-decrease coefficient.
+    pub c1: f64,
+    /// Maximum backtracking iterations per line search.
+    pub max_ls: usize,
+}
+
+impl Default for BfgsConfig {
+    fn default() -> Self {
+        Self {
+            bounds: Vec::new(),
+            max_iter: 100,
+            grad_tol: 1e-6,
+            step_init: 1.0,
+            step_contract: 0.5,
+            c1: 1e-4,
+            max_ls: 40,
+        }
+    }
+}
+
+/// Result of a projected-BFGS run.
+#[derive(Clone, Debug)]
+pub struct BfgsResult {
+    /// Best design vector found.
+    pub x: Vec<f64>,
+    /// Merit value at `x`.
+    pub f: f64,
+    /// Outer iterations performed.
+    pub iterations: usize,
+    /// Final gradient norm.
+    pub grad_norm: f64,
+    /// Whether the gradient-norm tolerance was met.
+    pub converged: bool,
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn clamp_to_bounds(x: &mut [f64], bounds: &[(f64, f64)]) {
+    for (xi, &(lo, hi)) in x.iter_mut().zip(bounds) {
+        *xi = xi.max(lo).min(hi);
+    }
+}
+
+/// Multiply the dense `n×n` inverse-Hessian `h` by the vector `v`.
+fn mat_vec(h: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    h.iter().map(|row| dot(row, v)).collect()
+}
+
+/// Minimize the `merit_fn` from `x0`, using `grad_fn` for analytic gradients.
+///
+/// `merit_fn` returns the scalar merit (objective plus constraint penalty) and
+/// is called at every line-search trial. `grad_fn` returns the merit gradient
+/// and is called once per accepted iterate.
+pub fn optimize<V, G>(config: &BfgsConfig, x0: &[f64], merit_fn: V, grad_fn: G) -> BfgsResult
+where
+    V: Fn(&[f64]) -> f64,
+    G: Fn(&[f64]) -> Vec<f64>,
+{
+    let n = x0.len();
+    let mut x = x0.to_vec();
+    clamp_to_bounds(&mut x, &config.bounds);
+
+    let mut f = merit_fn(&x);
+    let mut g = grad_fn(&x);
+
+    // Inverse-Hessian approximation, initialized to the identity.
+    let mut h: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    let mut iterations = 0;
+    let mut grad_norm = norm(&g);
+
+    while iterations < config.max_iter && grad_norm > config.grad_tol {
+        // Quasi-Newton direction d = -H g.
+        let hg = mat_vec(&h, &g);
+        let d: Vec<f64> = hg.iter().map(|v| -v).collect();
+
+        let slope = dot(&g, &d);
+        if slope >= 0.0 {
+            // Not a descent direction (rounding or an indefinite H); stop.
+            break;
+        }
+
+        // Backtracking Armijo line search along d.
+        let mut alpha = config.step_init;
+        let mut accepted = false;
+        let mut x_new = x.clone();
+        let mut f_new = f;
+        for _ in 0..config.max_ls {
+            x_new = x.iter().zip(&d).map(|(xi, di)| xi + alpha * di).collect();
+            clamp_to_bounds(&mut x_new, &config.bounds);
+            let fv = merit_fn(&x_new);
+            if fv <= f + config.c1 * alpha * slope {
+                f_new = fv;
+                accepted = true;
+                break;
+            }
+            alpha *= config.step_contract;
+        }
+
+        if !accepted {
+            // No improving step found; treat as a stationary point.
+            break;
+        }
+
+        let g_new = grad_fn(&x_new);
+
+        // BFGS inverse-Hessian update with the standard (s, y) rule.
+        let s: Vec<f64> = x_new.iter().zip(&x).map(|(a, b)| a - b).collect();
+        let y: Vec<f64> = g_new.iter().zip(&g).map(|(a, b)| a - b).collect();
+        let sy = dot(&s, &y);
+        if sy > 0.0 {
+            // H <- (I - rho s yᵀ) H (I - rho y sᵀ) + rho s sᵀ, rho = 1/(yᵀs).
+            let rho = 1.0 / sy;
+            let hy = mat_vec(&h, &y);
+            let yhy = dot(&y, &hy);
+            let mut h_new = h.clone();
+            for i in 0..n {
+                for j in 0..n {
+                    h_new[i][j] += rho * (1.0 + rho * yhy) * s[i] * s[j]
+                        - rho * (s[i] * hy[j] + hy[i] * s[j]);
+                }
+            }
+            h = h_new;
+        }
+
+        x = x_new;
+        f = f_new;
+        g = g_new;
+        grad_norm = norm(&g);
+        iterations += 1;
+    }
+
+    BfgsResult {
+        x,
+        f,
+        iterations,
+        grad_norm,
+        converged: grad_norm <= config.grad_tol,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quadratic_minimum() {
+        // Minimize (x-3)^2 + (y+1)^2; minimum at (3, -1).
+        let config = BfgsConfig {
+            bounds: vec![(-10.0, 10.0), (-10.0, 10.0)],
+            ..Default::default()
+        };
+        let merit = |x: &[f64]| (x[0] - 3.0).powi(2) + (x[1] + 1.0).powi(2);
+        let grad = |x: &[f64]| vec![2.0 * (x[0] - 3.0), 2.0 * (x[1] + 1.0)];
+        let res = optimize(&config, &[0.0, 0.0], merit, grad);
+        assert!(res.converged, "should converge on a convex quadratic");
+        assert!((res.x[0] - 3.0).abs() < 1e-3);
+        assert!((res.x[1] + 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_bounds_are_respected() {
+        // Unconstrained minimum at (3, 3) but the box caps both at 1.0.
+        let config = BfgsConfig {
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+        let merit = |x: &[f64]| (x[0] - 3.0).powi(2) + (x[1] - 3.0).powi(2);
+        let grad = |x: &[f64]| vec![2.0 * (x[0] - 3.0), 2.0 * (x[1] - 3.0)];
+        let res = optimize(&config, &[0.5, 0.5], merit, grad);
+        assert!(res.x[0] <= 1.0 + 1e-9 && res.x[1] <= 1.0 + 1e-9);
+        assert!(res.x[0] > 0.9 && res.x[1] > 0.9, "should ride the upper bound");
+    }
+
+    #[test]
+    fn test_penalty_keeps_constraint() {
+        // Minimize -x (wants x large) with an exact penalty for x > 1.
+        let config = BfgsConfig {
+            bounds: vec![(0.0, 5.0)],
+            ..Default::default()
+        };
+        let penalty = 100.0;
+        let merit = |x: &[f64]| -x[0] + penalty * (x[0] - 1.0).max(0.0);
+        let grad = |x: &[f64]| {
+            let active = if x[0] > 1.0 { penalty } else { 0.0 };
+            vec![-1.0 + active]
+        };
+        let res = optimize(&config, &[0.0], merit, grad);
+        assert!(res.x[0] <= 1.0 + 1e-2, "penalty should hold x near the limit");
+    }
+}
@@ -12,6 +12,14 @@ pub struct Root {
     pub components: Option<Components>,     // v2.4: Component model selection
     pub losses: Option<Losses>,             // v2.4: Loss coefficients
     pub degradation: Option<Degradation>,   // v2.4: Degradation scenarios
+    pub uncertainty: Option<Uncertainty>,   // v2.19: Monte Carlo uncertainty
+    pub design: Option<Design>,             // v2.24: inverse engine-sizing target
+    pub minimize: Option<Minimize>,         // v2.25: gradient-based local refinement
+    pub turbomachinery: Option<Turbomachinery>, // v2.28: multi-stage / multi-spool stack
+    pub objectives: Option<Vec<Objective>>, // v2.29: many-objective optimisation metrics
+    pub mission: Option<Vec<Segment>>,      // v2.33: mission-segment schedule
+    pub bottoming: Option<Bottoming>,       // v2.35: heat-recovery bottoming cycle
+    pub fuel: Option<Fuel>,                 // v2.37: named fuel / heating-value model
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -26,6 +34,111 @@ pub struct Solver {
     pub max_iter: i32,
     pub tol: f64,
     pub damping: f64,
+    /// Globalization mode: "damped" (default) or "lm" (Levenberg–Marquardt). v2.10
+    #[serde(default = "default_globalize")]
+    pub globalize: String,
+    /// Initial Levenberg–Marquardt damping parameter. v2.10
+    #[serde(default = "default_lm_lambda_init")]
+    pub lm_lambda_init: f64,
+    /// Factor the LM parameter is grown by on a rejected step. v2.10
+    #[serde(default = "default_lm_factor")]
+    pub lm_lambda_up: f64,
+    /// Factor the LM parameter is shrunk by on an accepted step. v2.10
+    #[serde(default = "default_lm_factor")]
+    pub lm_lambda_down: f64,
+    /// Divergence cap; the solve aborts once the LM parameter exceeds it. v2.10
+    #[serde(default = "default_lm_lambda_cap")]
+    pub lm_lambda_cap: f64,
+    /// Reuse the Jacobian via rank-1 quasi-Newton updates between true factorizations. v2.11
+    #[serde(default)]
+    pub jac_reuse: bool,
+    /// Force a fresh Jacobian every N iterations (0 = only on stagnation). v2.11
+    #[serde(default = "default_jac_refresh_interval")]
+    pub jac_refresh_interval: i32,
+    /// Refresh the Jacobian when the residual fails to drop by this factor. v2.11
+    #[serde(default = "default_jac_stagnation_factor")]
+    pub jac_stagnation_factor: f64,
+    /// Termination mode: "abs" (default), "rel", "step", or "safe". v2.12
+    #[serde(default = "default_term_mode")]
+    pub term_mode: String,
+    /// Absolute residual tolerance (defaults to `tol`). v2.12
+    #[serde(default)]
+    pub abstol: Option<f64>,
+    /// Relative residual tolerance (defaults derived from `tol`). v2.12
+    #[serde(default)]
+    pub reltol: Option<f64>,
+    /// Step-size tolerance (defaults to `tol`). v2.12
+    #[serde(default)]
+    pub stol: Option<f64>,
+    /// State floors that clamp intermediate quantities each iteration. v2.27
+    #[serde(default)]
+    pub floors: Option<Floors>,
+    /// Solve strategy: "damped" (default, the historical fixed-point/Newton
+    /// setup), "newton" (undamped full Newton step), or "continuation"
+    /// (homotopy march from an easy point to the target). v2.38
+    #[serde(default = "default_strategy")]
+    pub strategy: String,
+    /// Minimum number of iterations before convergence is accepted, to stop the
+    /// solver declaring success on a lucky initial residual. v2.38
+    #[serde(default = "default_min_iter")]
+    pub min_iter: i32,
+    /// Number of homotopy increments for the "continuation" strategy; `None`
+    /// outside that strategy. v2.38
+    #[serde(default)]
+    pub continuation_steps: Option<i32>,
+}
+
+fn default_strategy() -> String { "damped".to_string() }
+fn default_min_iter() -> i32 { 0 }
+
+/// Multiplicative lower-limit factors applied to intermediate solver state
+/// each Newton iteration (v2.27). Each quantity is clamped to
+/// `factor * reference` so it can never go non-positive or collapse toward
+/// zero during transients, mirroring the small non-dimensional floors robust
+/// turbulence solvers impose on `k`/`ω`. A factor of `0` disables that floor.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Floors {
+    /// Floor factor on station total pressures.
+    #[serde(default = "default_floor")]
+    pub pressure: f64,
+    /// Floor factor on station total temperatures.
+    #[serde(default = "default_floor")]
+    pub temperature: f64,
+    /// Floor factor on mass fractions.
+    #[serde(default = "default_floor")]
+    pub mass_fraction: f64,
+}
+
+impl Default for Floors {
+    fn default() -> Self {
+        Self {
+            pressure: default_floor(),
+            temperature: default_floor(),
+            mass_fraction: default_floor(),
+        }
+    }
+}
+
+fn default_floor() -> f64 { 1e-6 }
+
+fn default_term_mode() -> String { "abs".to_string() }
+
+fn default_jac_refresh_interval() -> i32 { 0 }
+fn default_jac_stagnation_factor() -> f64 { 0.9 }
+
+fn default_globalize() -> String { "damped".to_string() }
+fn default_lm_lambda_init() -> f64 { 1e-3 }
+fn default_lm_factor() -> f64 { 10.0 }
+fn default_lm_lambda_cap() -> f64 { 1e12 }
+
+impl Solver {
+    /// FFI globalization selector: 0 = damped Newton, 1 = Levenberg–Marquardt. v2.10
+    pub fn globalize_id(&self) -> i32 {
+        match self.globalize.as_str() {
+            "lm" => 1,
+            _ => 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -51,6 +164,41 @@ pub struct Cycle {
     pub fuel_k: f64,
 }
 
+// v2.33: A single mission segment. Instead of a single design point, the user
+// declares an ordered list of operating segments (takeoff, climb, cruise, ...),
+// each with its own flight condition, throttle setting, and duration. Each
+// segment inherits the `[cycle]` efficiencies, pressure ratio, and bypass ratio
+// unless it overrides them; the mission run aggregates integrated fuel burn,
+// time-weighted TSFC, and peak T4 across the schedule.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Segment {
+    /// Segment flight Mach number.
+    pub mach: f64,
+    /// Segment altitude in kilometres.
+    pub alt_km: f64,
+    /// Throttle / thrust-fraction setting, applied as a multiplier on the fuel
+    /// parameter (1.0 = the cycle's design fuelling).
+    #[serde(default = "default_one")]
+    pub throttle: f64,
+    /// Segment duration in seconds, used to integrate fuel burn and weight TSFC.
+    pub duration_s: f64,
+    /// Bypass-ratio override (defaults to `cycle.bpr`).
+    #[serde(default)]
+    pub bpr: Option<f64>,
+    /// Overall-pressure-ratio override (defaults to `cycle.opr`).
+    #[serde(default)]
+    pub opr: Option<f64>,
+    /// Compressor efficiency override (defaults to `cycle.eta_comp`).
+    #[serde(default)]
+    pub eta_comp: Option<f64>,
+    /// Turbine efficiency override (defaults to `cycle.eta_turb`).
+    #[serde(default)]
+    pub eta_turb: Option<f64>,
+    /// Nozzle efficiency override (defaults to `cycle.eta_nozz`).
+    #[serde(default)]
+    pub eta_nozz: Option<f64>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Sweep {
     pub bpr_min: f64,
@@ -59,6 +207,211 @@ pub struct Sweep {
     pub opr_min: f64,
     pub opr_max: f64,
     pub opr_n: usize,
+    /// Sampling mode (v2.36): "grid" (default, dense rectangular BPR×OPR grid),
+    /// "lhs" (Latin hypercube), or "sobol" (Sobol quasi-random). The space-
+    /// filling modes draw `n_samples` points over the same BPR/OPR ranges,
+    /// producing exploration/training datasets instead of a full grid.
+    #[serde(default = "default_sampling_mode")]
+    pub mode: String,
+    /// Number of points for the `lhs`/`sobol` space-filling modes.
+    #[serde(default)]
+    pub n_samples: Option<usize>,
+    /// Seed for the `lhs` sampler (the `sobol` sequence is deterministic).
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_sampling_mode() -> String { "grid".to_string() }
+
+// v2.24: Inverse "design" target. Instead of fixing the free cycle parameter
+// and reading out thrust, the user pins a flow quantity and the solver
+// back-solves for the free parameter that hits it — the mass-flow-specified
+// analogue of a fixed total-pressure boundary condition.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Design {
+    /// Quantity to match: "thrust" (default) or "mass_flow".
+    #[serde(default = "default_design_target")]
+    pub target: String,
+    /// Desired value of the target quantity.
+    pub value: f64,
+    /// Free parameter to back-solve for: "bpr" (default) or "opr".
+    #[serde(default = "default_design_free")]
+    pub free: String,
+    /// Lower bound of the free-parameter search interval.
+    pub min: f64,
+    /// Upper bound of the free-parameter search interval.
+    pub max: f64,
+}
+
+fn default_design_target() -> String { "thrust".to_string() }
+fn default_design_free() -> String { "bpr".to_string() }
+
+// v2.25: Gradient-based local refinement (projected BFGS) of a single
+// scalarized objective over the NSGA-II bound box, with `t4 <= t4_max`
+// handled as an exact penalty on the merit function.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Minimize {
+    /// Weight on TSFC in the scalarized objective.
+    #[serde(default = "default_w_tsfc")]
+    pub w_tsfc: f64,
+    /// Weight on negated thrust (so larger thrust lowers the objective).
+    #[serde(default = "default_w_thrust")]
+    pub w_thrust: f64,
+    /// Exact-penalty coefficient on the `t4 <= t4_max` constraint.
+    #[serde(default = "default_penalty")]
+    pub penalty: f64,
+    /// Maximum outer BFGS iterations.
+    #[serde(default = "default_minimize_iter")]
+    pub max_iter: usize,
+    /// Gradient-norm convergence tolerance.
+    #[serde(default = "default_grad_tol")]
+    pub grad_tol: f64,
+}
+
+impl Default for Minimize {
+    fn default() -> Self {
+        Self {
+            w_tsfc: default_w_tsfc(),
+            w_thrust: default_w_thrust(),
+            penalty: default_penalty(),
+            max_iter: default_minimize_iter(),
+            grad_tol: default_grad_tol(),
+        }
+    }
+}
+
+fn default_w_tsfc() -> f64 { 1.0 }
+fn default_w_thrust() -> f64 { 1.0 }
+fn default_penalty() -> f64 { 1e3 }
+fn default_minimize_iter() -> usize { 100 }
+fn default_grad_tol() -> f64 { 1e-6 }
+
+// v2.28: Multi-stage / multi-spool turbomachinery architecture. Instead of
+// two lumped isentropic efficiencies and a single overall pressure ratio, the
+// user declares a stack of compressor and turbine stages, each with its own
+// stage pressure ratio and polytropic efficiency. The solve stacks the stage
+// pressure ratios multiplicatively and rolls the polytropic efficiencies up
+// into the equivalent overall isentropic efficiencies the FFI already consumes,
+// so `cycle.opr`/`cycle.eta_comp`/`cycle.eta_turb` become *derived* aggregates.
+// An optional LP/HP spool split records how the compressor stack is divided
+// between the two spools; it is reported and forwarded to the solver but does
+// not change the aggregate roll-up.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Turbomachinery {
+    /// Compressor stages, inlet-to-outlet.
+    pub compressor: Vec<Stage>,
+    /// Turbine stages, inlet-to-outlet.
+    pub turbine: Vec<Stage>,
+    /// Split the compressor stack into a low-pressure spool of this many stages
+    /// followed by a high-pressure spool (two-spool architecture). `0` (default)
+    /// keeps the engine single-spool.
+    #[serde(default)]
+    pub lp_compressor_stages: usize,
+}
+
+/// A single compressor or turbine stage. For a compressor the pressure ratio is
+/// the total-pressure rise across the stage (> 1); for a turbine it is the
+/// expansion ratio `p_in / p_out` (> 1). The polytropic (small-stage) efficiency
+/// is stage-size-invariant, which is what makes stacking well defined.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stage {
+    /// Stage total-pressure ratio (rise for a compressor, drop for a turbine).
+    pub pressure_ratio: f64,
+    /// Stage polytropic efficiency.
+    pub polytropic_eff: f64,
+}
+
+/// Ratio of specific heats used when converting polytropic efficiencies to the
+/// equivalent overall isentropic efficiencies (cold-air-standard value).
+const GAMMA: f64 = 1.4;
+
+impl Turbomachinery {
+    /// Overall compressor pressure ratio: the product of the stage ratios.
+    pub fn overall_opr(&self) -> f64 {
+        self.compressor.iter().map(|s| s.pressure_ratio).product()
+    }
+
+    /// Overall turbine expansion ratio: the product of the stage ratios.
+    pub fn overall_turbine_ratio(&self) -> f64 {
+        self.turbine.iter().map(|s| s.pressure_ratio).product()
+    }
+
+    /// Mass-averaged compressor polytropic efficiency across the stack, weighted
+    /// by each stage's logarithmic pressure rise (its share of the total work).
+    fn mean_compressor_poly(&self) -> f64 {
+        weighted_poly(&self.compressor)
+    }
+
+    /// Mass-averaged turbine polytropic efficiency across the stack.
+    fn mean_turbine_poly(&self) -> f64 {
+        weighted_poly(&self.turbine)
+    }
+
+    /// Equivalent overall compressor isentropic efficiency for the stacked
+    /// polytropic stages at the aggregate pressure ratio.
+    pub fn aggregate_eta_comp(&self) -> f64 {
+        let pr = self.overall_opr();
+        let ep = self.mean_compressor_poly();
+        let x = (GAMMA - 1.0) / GAMMA;
+        (pr.powf(x) - 1.0) / (pr.powf(x / ep) - 1.0)
+    }
+
+    /// Equivalent overall turbine isentropic efficiency for the stacked
+    /// polytropic stages at the aggregate expansion ratio.
+    pub fn aggregate_eta_turb(&self) -> f64 {
+        let pr = self.overall_turbine_ratio();
+        let ep = self.mean_turbine_poly();
+        let x = (GAMMA - 1.0) / GAMMA;
+        (1.0 - pr.powf(-x * ep)) / (1.0 - pr.powf(-x))
+    }
+
+    /// True when an LP/HP spool split has been declared.
+    pub fn two_spool(&self) -> bool {
+        self.lp_compressor_stages > 0 && self.lp_compressor_stages < self.compressor.len()
+    }
+}
+
+/// Work-weighted average of the stage polytropic efficiencies.
+fn weighted_poly(stages: &[Stage]) -> f64 {
+    let mut num = 0.0;
+    let mut den = 0.0;
+    for s in stages {
+        let w = s.pressure_ratio.ln();
+        num += w * s.polytropic_eff;
+        den += w;
+    }
+    if den > 0.0 {
+        num / den
+    } else {
+        0.0
+    }
+}
+
+// v2.29: Optimisation objective. NSGA-II can trade off an arbitrary list of
+// engine metrics simultaneously instead of only TSFC vs. thrust; each objective
+// names a metric and the sense in which it is optimised. The optimiser works in
+// minimisation space, so a "max" objective enters its internal vector negated.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Objective {
+    /// Metric to optimise: "tsfc", "thrust", "t4", or "fuel_burn".
+    pub metric: String,
+    /// Optimisation sense: "min" or "max". Defaults to the metric's natural
+    /// sense (minimise tsfc/t4/fuel_burn, maximise thrust).
+    #[serde(default)]
+    pub sense: Option<String>,
+}
+
+impl Objective {
+    /// Effective sense, falling back to the metric's natural direction.
+    pub fn sense(&self) -> &str {
+        match self.sense.as_deref() {
+            Some(s) => s,
+            None => match self.metric.as_str() {
+                "thrust" => "max",
+                _ => "min",
+            },
+        }
+    }
 }
 
 // v2.4: Component model configuration
@@ -73,6 +426,102 @@ pub struct Components {
     /// Nozzle model: "standard" or "advanced"
     #[serde(default = "default_model")]
     pub nozzle: String,
+    /// Gas model: "perfect" (default), "nasa_air", or "nasa_combustion". v2.15
+    #[serde(default = "default_gas_model")]
+    pub gas_model: String,
+    /// Optional characteristic maps giving part-load component behaviour as a
+    /// function of a corrected operating-point variable. v2.32
+    #[serde(default)]
+    pub maps: Option<ComponentMaps>,
+}
+
+// v2.32: Per-component characteristic maps. Instead of a single efficiency
+// number per component, a user may supply a curve giving efficiency (and, for
+// the compressor/turbine, pressure-ratio scaling) as a function of a corrected
+// operating-point variable such as corrected mass flow or corrected speed.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ComponentMaps {
+    /// Compressor characteristic line.
+    #[serde(default)]
+    pub compressor: Option<CharLine>,
+    /// Turbine characteristic line.
+    #[serde(default)]
+    pub turbine: Option<CharLine>,
+    /// Nozzle characteristic line.
+    #[serde(default)]
+    pub nozzle: Option<CharLine>,
+}
+
+/// A piecewise-linear characteristic curve `y = f(x)` with strictly increasing
+/// abscissae `x`. Outside the tabulated range the end values are held (no
+/// extrapolation). When no points are supplied the flat `default` is returned.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CharLine {
+    /// Corrected operating-point abscissae, strictly increasing.
+    #[serde(default)]
+    pub x: Vec<f64>,
+    /// Curve value at each abscissa.
+    #[serde(default)]
+    pub y: Vec<f64>,
+    /// Flat fallback value used when no `(x, y)` points are given.
+    #[serde(default)]
+    pub default: Option<f64>,
+}
+
+impl CharLine {
+    /// Evaluate the curve at operating point `q` by linear interpolation of the
+    /// bracketing interval, clamping to the end values outside the range.
+    pub fn eval(&self, q: f64) -> f64 {
+        if self.x.is_empty() {
+            return self.default.unwrap_or(0.0);
+        }
+        if q <= self.x[0] {
+            return self.y[0];
+        }
+        let last = self.x.len() - 1;
+        if q >= self.x[last] {
+            return self.y[last];
+        }
+
+        // Binary search for the interval x[i] <= q <= x[i+1].
+        let mut lo = 0;
+        let mut hi = last;
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if self.x[mid] <= q {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (x0, x1) = (self.x[lo], self.x[hi]);
+        let (y0, y1) = (self.y[lo], self.y[hi]);
+        y0 + (y1 - y0) * (q - x0) / (x1 - x0)
+    }
+
+    /// Validate the curve: `x`/`y` must match in length, `x` must be strictly
+    /// increasing, and at least one point is required unless a `default` is set.
+    pub fn validate(&self, label: &str) -> Result<()> {
+        if self.x.len() != self.y.len() {
+            bail!("{} map x and y must have equal length", label);
+        }
+        if self.x.is_empty() {
+            if self.default.is_none() {
+                bail!("{} map must supply points or a default value", label);
+            }
+            return Ok(());
+        }
+        for w in self.x.windows(2) {
+            if w[1] <= w[0] {
+                bail!("{} map x must be strictly increasing", label);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_gas_model() -> String {
+    "perfect".to_string()
 }
 
 fn default_model() -> String {
@@ -85,6 +534,8 @@ impl Default for Components {
             compressor: "standard".to_string(),
             turbine: "standard".to_string(),
             nozzle: "standard".to_string(),
+            gas_model: "perfect".to_string(),
+            maps: None,
         }
     }
 }
@@ -110,6 +561,153 @@ impl Components {
             _ => 0,
         }
     }
+
+    /// Gas-model FFI selector. v2.15
+    pub fn gas_model_id(&self) -> i32 {
+        crate::thermo::GasModel::parse(&self.gas_model)
+            .map(|m| m.id())
+            .unwrap_or(0)
+    }
+}
+
+// v2.35: Heat-recovery bottoming cycle. A heat-recovery steam generator (HRSG)
+// captures exhaust enthalpy downstream of the turbine/nozzle and feeds a steam
+// turbine, turning the pure gas-turbine model into a combined-cycle estimator.
+// The recovered heat is taken from the exhaust temperature above a configurable
+// stack temperature (less the HRSG pinch), scaled by the heat-exchanger
+// effectiveness; the steam turbine then converts it at its own efficiency. The
+// bottoming cycle works on the heat the topping cycle rejected, so the combined
+// efficiency is `eta_topping + eta_bottoming·(1 - eta_topping)`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Bottoming {
+    /// HRSG heat-exchanger effectiveness (0–1).
+    pub hrsg_effectiveness: f64,
+    /// Steam-turbine isentropic efficiency (0–1).
+    pub steam_turbine_eta: f64,
+    /// Pinch-point temperature difference in kelvin between the gas and steam
+    /// sides; heat below this approach is not recoverable.
+    pub pinch_dt_k: f64,
+    /// Stack (flue-gas exit) temperature in kelvin; exhaust heat is only
+    /// recovered down to this floor.
+    #[serde(default = "default_stack_temp")]
+    pub stack_temp_k: f64,
+    /// Whether the HRSG imposes a back-pressure penalty on the gas path.
+    #[serde(default)]
+    pub back_pressure: bool,
+}
+
+fn default_stack_temp() -> f64 { 400.0 }
+
+impl Bottoming {
+    /// Recoverable exhaust heat proxy, expressed as the usable temperature drop
+    /// (kelvin) above the stack temperature after the pinch, scaled by the HRSG
+    /// effectiveness.
+    pub fn recovered_heat(&self, exhaust_temp_k: f64) -> f64 {
+        let usable = (exhaust_temp_k - self.stack_temp_k - self.pinch_dt_k).max(0.0);
+        self.hrsg_effectiveness * usable
+    }
+
+    /// Bottoming-cycle shaft-power proxy: recovered heat converted through the
+    /// steam-turbine efficiency.
+    pub fn bottoming_power(&self, exhaust_temp_k: f64) -> f64 {
+        self.steam_turbine_eta * self.recovered_heat(exhaust_temp_k)
+    }
+
+    /// Combined-cycle thermal efficiency from the topping-cycle efficiency and
+    /// the exhaust temperature. The bottoming efficiency is the Carnot-limited
+    /// recovery fraction of the rejected heat scaled by the HRSG and steam
+    /// efficiencies; the bottoming cycle only sees the heat the topping cycle
+    /// rejected, hence the `(1 - eta_topping)` weighting.
+    pub fn combined_efficiency(&self, topping_eta: f64, exhaust_temp_k: f64) -> f64 {
+        if exhaust_temp_k <= 0.0 {
+            return topping_eta;
+        }
+        let usable = (exhaust_temp_k - self.stack_temp_k - self.pinch_dt_k).max(0.0);
+        let carnot = usable / exhaust_temp_k;
+        let eta_bottoming = self.hrsg_effectiveness * self.steam_turbine_eta * carnot;
+        topping_eta + eta_bottoming * (1.0 - topping_eta)
+    }
+}
+
+// v2.37: Named fuel / heating-value model. The scalar `cycle.fuel_k` lumps the
+// fuel's energy content into an opaque constant, which makes comparing fuels
+// (kerosene vs hydrogen vs methane) a hand-tuning exercise. With a `[fuel]`
+// section the combustor heat addition and fuel flow are derived instead from
+// the fuel's lower heating value and the commanded turbine-inlet temperature:
+// the fuel-air ratio needed to raise the gas from the reference temperature to
+// `constraints.t4_max` is `far = cp·(T4 - T_ref) / (LHV·1e6)`, and the
+// equivalence ratio is `phi = far / far_stoich`. Since the Fortran core only
+// accepts the scalar `fuel_k`, a present fuel model rescales `fuel_k` inversely
+// with the heating value relative to the Jet-A reference, so a high-LHV fuel
+// such as hydrogen burns proportionally less mass for the same heat release.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Fuel {
+    /// Named fuel preset: "jet_a" (default), "hydrogen", or "methane". When the
+    /// heating value / stoichiometric FAR are omitted they are filled from the
+    /// preset; explicit values always override the preset.
+    #[serde(default = "default_fuel_type")]
+    pub fuel_type: String,
+    /// Lower heating value in MJ/kg. Filled from the preset when omitted.
+    #[serde(default)]
+    pub lhv_mj_kg: Option<f64>,
+    /// Stoichiometric fuel-air ratio. Filled from the preset when omitted.
+    #[serde(default)]
+    pub far_stoich: Option<f64>,
+}
+
+fn default_fuel_type() -> String { "jet_a".to_string() }
+
+/// Reference lower heating value (Jet-A, MJ/kg) used to rescale the opaque
+/// `fuel_k` when a fuel model is active.
+const JET_A_LHV_MJ_KG: f64 = 43.0;
+/// Mean combustion-gas specific heat (J/kg/K) for the reduced-order FAR proxy.
+const FUEL_CP: f64 = 1150.0;
+/// Compressor-discharge reference temperature (K) the combustor heats from in
+/// the reduced-order FAR proxy.
+const FUEL_T_REF: f64 = 800.0;
+
+impl Fuel {
+    /// Built-in `(lhv_mj_kg, far_stoich)` presets for a named fuel, or `None`
+    /// for an unrecognised name.
+    fn preset(name: &str) -> Option<(f64, f64)> {
+        match name {
+            "jet_a" => Some((43.0, 0.068)),
+            "hydrogen" => Some((120.0, 0.029)),
+            "methane" => Some((50.0, 0.058)),
+            _ => None,
+        }
+    }
+
+    /// Effective `(lhv_mj_kg, far_stoich)`, taking explicit fields first and
+    /// falling back to the named preset. Returns `None` if neither an explicit
+    /// value nor a recognised preset supplies a field.
+    pub fn resolve(&self) -> Option<(f64, f64)> {
+        let preset = Self::preset(&self.fuel_type);
+        let lhv = self.lhv_mj_kg.or(preset.map(|p| p.0))?;
+        let far = self.far_stoich.or(preset.map(|p| p.1))?;
+        Some((lhv, far))
+    }
+
+    /// Combustor fuel-air ratio required to reach `t4_k` from the reference
+    /// temperature, from an energy balance on the resolved heating value.
+    pub fn combustor_far(&self, t4_k: f64) -> f64 {
+        let (lhv, _) = self.resolve().unwrap_or((JET_A_LHV_MJ_KG, 0.068));
+        (FUEL_CP * (t4_k - FUEL_T_REF).max(0.0)) / (lhv * 1.0e6)
+    }
+
+    /// Equivalence ratio `phi = far / far_stoich` at the commanded `t4_k`.
+    pub fn equivalence_ratio(&self, t4_k: f64) -> f64 {
+        let (_, far_stoich) = self.resolve().unwrap_or((JET_A_LHV_MJ_KG, 0.068));
+        self.combustor_far(t4_k) / far_stoich
+    }
+
+    /// Rescale the opaque `fuel_k` for the resolved heating value relative to
+    /// the Jet-A reference. A higher LHV releases the same heat per unit air
+    /// from less fuel mass, so `fuel_k` scales as `LHV_ref / LHV`.
+    pub fn effective_fuel_k(&self, base_fuel_k: f64) -> f64 {
+        let (lhv, _) = self.resolve().unwrap_or((JET_A_LHV_MJ_KG, 0.068));
+        base_fuel_k * JET_A_LHV_MJ_KG / lhv
+    }
 }
 
 // v2.4: Loss coefficient configuration
@@ -160,8 +758,27 @@ pub struct Degradation {
     /// Scenario name/description
     #[serde(default)]
     pub scenario_name: String,
+    /// Operating hours over which the trajectory rates act (v2.34). When set,
+    /// the effective factors/adder are computed from the per-1000-hour rates
+    /// below instead of being taken from the discrete preset values above.
+    #[serde(default)]
+    pub hours: Option<f64>,
+    /// Compressor efficiency deterioration rate per 1000 operating hours.
+    #[serde(default = "default_zero")]
+    pub eta_comp_rate: f64,
+    /// Turbine efficiency deterioration rate per 1000 operating hours.
+    #[serde(default = "default_zero")]
+    pub eta_turb_rate: f64,
+    /// Pressure-loss accumulation rate per 1000 operating hours.
+    #[serde(default = "default_zero")]
+    pub loss_rate: f64,
+    /// Trajectory model: "linear" (default) or "exponential".
+    #[serde(default = "default_deg_model")]
+    pub model: String,
 }
 
+fn default_deg_model() -> String { "linear".to_string() }
+
 fn default_one() -> f64 { 1.0 }
 fn default_zero() -> f64 { 0.0 }
 
@@ -172,6 +789,11 @@ impl Default for Degradation {
             eta_turb_factor: 1.0,
             loss_adder: 0.0,
             scenario_name: "nominal".to_string(),
+            hours: None,
+            eta_comp_rate: 0.0,
+            eta_turb_rate: 0.0,
+            loss_rate: 0.0,
+            model: default_deg_model(),
         }
     }
 }
@@ -184,6 +806,7 @@ impl Degradation {
             eta_turb_factor: 0.97,
             loss_adder: 0.01,
             scenario_name: "light".to_string(),
+            ..Default::default()
         }
     }
 
@@ -194,6 +817,7 @@ impl Degradation {
             eta_turb_factor: 0.94,
             loss_adder: 0.02,
             scenario_name: "moderate".to_string(),
+            ..Default::default()
         }
     }
 
@@ -204,6 +828,7 @@ impl Degradation {
             eta_turb_factor: 0.91,
             loss_adder: 0.03,
             scenario_name: "severe".to_string(),
+            ..Default::default()
         }
     }
 
@@ -211,6 +836,99 @@ impl Degradation {
     pub fn is_degraded(&self) -> bool {
         self.eta_comp_factor < 1.0 || self.eta_turb_factor < 1.0 || self.loss_adder > 0.0
     }
+
+    /// Whether this scenario is driven by a time-resolved trajectory (v2.34).
+    pub fn is_trajectory(&self) -> bool {
+        self.hours.is_some()
+    }
+
+    /// Resolve the effective efficiency factors and loss adder from the
+    /// trajectory rates over `hours` operating hours (v2.34). For the `linear`
+    /// model a factor is `1 - rate·(hours/1000)`; for `exponential` it is
+    /// `exp(-rate·(hours/1000))`. Returns the discrete preset values unchanged
+    /// when no trajectory is configured.
+    pub fn effective(&self) -> (f64, f64, f64) {
+        match self.hours {
+            None => (self.eta_comp_factor, self.eta_turb_factor, self.loss_adder),
+            Some(hours) => {
+                let kilohours = hours / 1000.0;
+                let exponential = self.model == "exponential";
+                let factor = |rate: f64| {
+                    if exponential {
+                        (-rate * kilohours).exp()
+                    } else {
+                        1.0 - rate * kilohours
+                    }
+                };
+                (
+                    factor(self.eta_comp_rate),
+                    factor(self.eta_turb_rate),
+                    self.loss_rate * kilohours,
+                )
+            }
+        }
+    }
+}
+
+// v2.19: Monte Carlo uncertainty configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Uncertainty {
+    /// Number of Monte Carlo samples
+    #[serde(default = "default_uq_samples")]
+    pub samples: usize,
+    /// Seed for the Latin-hypercube sampler
+    #[serde(default = "default_uq_seed")]
+    pub seed: u64,
+    /// Number of quantized CDF points per output in the JSON summary
+    #[serde(default = "default_uq_cdf_points")]
+    pub cdf_points: usize,
+    /// Per-parameter input distributions
+    #[serde(default)]
+    pub parameters: Vec<ParamDist>,
+}
+
+fn default_uq_samples() -> usize { 512 }
+fn default_uq_seed() -> u64 { 42 }
+fn default_uq_cdf_points() -> usize { 21 }
+
+/// A single uncertain input parameter with its marginal distribution.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ParamDist {
+    /// Parameter name: eta_comp, eta_turb, bpr, opr, or losses.{inlet,burner,turbine,nozzle}
+    pub name: String,
+    /// Distribution family: "normal" or "uniform"
+    pub dist: String,
+    /// Mean (normal)
+    #[serde(default)]
+    pub mean: Option<f64>,
+    /// Standard deviation (normal)
+    #[serde(default)]
+    pub std: Option<f64>,
+    /// Lower bound (uniform)
+    #[serde(default)]
+    pub min: Option<f64>,
+    /// Upper bound (uniform)
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+impl ParamDist {
+    /// Invert the marginal CDF at the unit-interval quantile `u`.
+    pub fn invert(&self, u: f64) -> f64 {
+        match self.dist.as_str() {
+            "uniform" => {
+                let lo = self.min.unwrap_or(0.0);
+                let hi = self.max.unwrap_or(1.0);
+                lo + u * (hi - lo)
+            }
+            _ => {
+                // normal
+                let mean = self.mean.unwrap_or(0.0);
+                let std = self.std.unwrap_or(1.0);
+                mean + std * crate::uncertainty::norm_ppf(u)
+            }
+        }
+    }
 }
 
 impl Root {
@@ -224,9 +942,46 @@ impl Root {
         if !(0.0 < self.solver.damping && self.solver.damping <= 1.0) {
             bail!("solver.damping must be in (0, 1]");
         }
+        // v2.38: pluggable solve strategy with optional continuation/homotopy.
+        if !["damped", "newton", "continuation"].contains(&self.solver.strategy.as_str()) {
+            bail!("solver.strategy must be 'damped', 'newton', or 'continuation'");
+        }
+        if self.solver.min_iter < 0 {
+            bail!("solver.min_iter must be non-negative");
+        }
+        if self.solver.min_iter > self.solver.max_iter {
+            bail!("solver.min_iter must be <= solver.max_iter");
+        }
+        if self.solver.strategy == "continuation" {
+            match self.solver.continuation_steps {
+                Some(n) if n >= 1 => {}
+                _ => bail!("solver.continuation_steps must be >= 1 for the 'continuation' strategy"),
+            }
+        }
         if self.solver.tol <= 0.0 {
             bail!("solver.tol must be positive");
         }
+        if !["damped", "lm"].contains(&self.solver.globalize.as_str()) {
+            bail!("solver.globalize must be 'damped' or 'lm'");
+        }
+        if self.solver.lm_lambda_init <= 0.0 {
+            bail!("solver.lm_lambda_init must be positive");
+        }
+        if self.solver.lm_lambda_up <= 1.0 || self.solver.lm_lambda_down <= 1.0 {
+            bail!("solver.lm_lambda_up and lm_lambda_down must be > 1");
+        }
+        if self.solver.lm_lambda_cap <= self.solver.lm_lambda_init {
+            bail!("solver.lm_lambda_cap must exceed lm_lambda_init");
+        }
+        if self.solver.jac_refresh_interval < 0 {
+            bail!("solver.jac_refresh_interval must be >= 0");
+        }
+        if !(0.0..1.0).contains(&self.solver.jac_stagnation_factor) {
+            bail!("solver.jac_stagnation_factor must be in [0, 1)");
+        }
+        if crate::termination::TerminationMode::parse(&self.solver.term_mode).is_none() {
+            bail!("solver.term_mode must be 'abs', 'rel', 'step', or 'safe'");
+        }
         if self.constraints.t4_max <= 0.0 {
             bail!("constraints.t4_max must be positive");
         }
@@ -246,6 +1001,24 @@ impl Root {
             bail!("cycle.eta_nozz must be in [0, 1]");
         }
 
+        // v2.33: Validate mission schedule if present
+        if let Some(ref mission) = self.mission {
+            if mission.is_empty() {
+                bail!("mission must declare at least one segment");
+            }
+            for (i, seg) in mission.iter().enumerate() {
+                if !(0.0..=0.95).contains(&seg.mach) {
+                    bail!("mission segment {} mach must be in [0, 0.95]", i + 1);
+                }
+                if !(0.0..=20.0).contains(&seg.alt_km) {
+                    bail!("mission segment {} alt_km must be in [0, 20]", i + 1);
+                }
+                if seg.duration_s <= 0.0 {
+                    bail!("mission segment {} duration_s must be positive", i + 1);
+                }
+            }
+        }
+
         // Validate sweep if present
         if let Some(ref sweep) = self.sweep {
             if sweep.bpr_n == 0 || sweep.opr_n == 0 {
@@ -257,6 +1030,16 @@ impl Root {
             if sweep.opr_min > sweep.opr_max {
                 bail!("sweep.opr_min must be <= sweep.opr_max");
             }
+            // v2.36: space-filling sampling modes.
+            if !["grid", "lhs", "sobol"].contains(&sweep.mode.as_str()) {
+                bail!("sweep.mode must be 'grid', 'lhs', or 'sobol'");
+            }
+            if sweep.mode != "grid" {
+                match sweep.n_samples {
+                    Some(n) if n >= 1 => {}
+                    _ => bail!("sweep.n_samples must be >= 1 for the '{}' sampling mode", sweep.mode),
+                }
+            }
         }
 
         // v2.4: Validate components if present
@@ -271,6 +1054,20 @@ impl Root {
             if !valid_models.contains(&comp.nozzle.as_str()) {
                 bail!("components.nozzle must be 'standard' or 'advanced'");
             }
+            if crate::thermo::GasModel::parse(&comp.gas_model).is_none() {
+                bail!("components.gas_model must be 'perfect', 'nasa_air', or 'nasa_combustion'");
+            }
+            if let Some(ref maps) = comp.maps {
+                if let Some(ref c) = maps.compressor {
+                    c.validate("components.maps.compressor")?;
+                }
+                if let Some(ref t) = maps.turbine {
+                    t.validate("components.maps.turbine")?;
+                }
+                if let Some(ref n) = maps.nozzle {
+                    n.validate("components.maps.nozzle")?;
+                }
+            }
         }
 
         // v2.4: Validate losses if present
@@ -289,19 +1086,143 @@ impl Root {
             }
         }
 
-        // v2.4: Validate degradation if present
+        // v2.4: Validate degradation if present. v2.34: when a time-resolved
+        // trajectory is configured the effective factors/adder are derived from
+        // the rates and checked against the same windows, so a trajectory that
+        // would over-deteriorate the engine is rejected up front.
         if let Some(ref deg) = self.degradation {
-            if !(0.5..=1.0).contains(&deg.eta_comp_factor) {
+            if deg.is_trajectory() {
+                if !["linear", "exponential"].contains(&deg.model.as_str()) {
+                    bail!("degradation.model must be 'linear' or 'exponential'");
+                }
+                if deg.hours.unwrap() < 0.0 {
+                    bail!("degradation.hours must be non-negative");
+                }
+            }
+            let (eta_comp_factor, eta_turb_factor, loss_adder) = deg.effective();
+            if !(0.5..=1.0).contains(&eta_comp_factor) {
                 bail!("degradation.eta_comp_factor must be in [0.5, 1.0]");
             }
-            if !(0.5..=1.0).contains(&deg.eta_turb_factor) {
+            if !(0.5..=1.0).contains(&eta_turb_factor) {
                 bail!("degradation.eta_turb_factor must be in [0.5, 1.0]");
             }
-            if !(0.0..=0.2).contains(&deg.loss_adder) {
+            if !(0.0..=0.2).contains(&loss_adder) {
                 bail!("degradation.loss_adder must be in [0, 0.2]");
             }
         }
 
+        // v2.35: Validate bottoming cycle if present
+        if let Some(ref b) = self.bottoming {
+            if self.cycle.eta_turb <= 0.0 {
+                bail!("bottoming cycle requires a turbine in the cycle (cycle.eta_turb must be > 0)");
+            }
+            if !(0.0..=1.0).contains(&b.hrsg_effectiveness) {
+                bail!("bottoming.hrsg_effectiveness must be in [0, 1]");
+            }
+            if !(0.0..=1.0).contains(&b.steam_turbine_eta) {
+                bail!("bottoming.steam_turbine_eta must be in [0, 1]");
+            }
+            if b.pinch_dt_k <= 0.0 {
+                bail!("bottoming.pinch_dt_k must be positive");
+            }
+            if b.stack_temp_k <= 0.0 {
+                bail!("bottoming.stack_temp_k must be positive");
+            }
+        }
+
+        // v2.37: Validate fuel model if present
+        if let Some(ref fuel) = self.fuel {
+            match fuel.resolve() {
+                None => bail!(
+                    "fuel.fuel_type '{}' is unknown; supply lhv_mj_kg and far_stoich explicitly or use 'jet_a', 'hydrogen', or 'methane'",
+                    fuel.fuel_type
+                ),
+                Some((lhv, far_stoich)) => {
+                    if lhv <= 0.0 {
+                        bail!("fuel.lhv_mj_kg must be positive");
+                    }
+                    if !(0.0 < far_stoich && far_stoich < 1.0) {
+                        bail!("fuel.far_stoich must be in (0, 1)");
+                    }
+                }
+            }
+        }
+
+        // v2.28: Validate turbomachinery stack if present
+        if let Some(ref tm) = self.turbomachinery {
+            if tm.compressor.is_empty() {
+                bail!("turbomachinery.compressor must declare at least one stage");
+            }
+            if tm.turbine.is_empty() {
+                bail!("turbomachinery.turbine must declare at least one stage");
+            }
+            for (kind, stages) in [("compressor", &tm.compressor), ("turbine", &tm.turbine)] {
+                for (i, s) in stages.iter().enumerate() {
+                    if s.pressure_ratio <= 1.0 {
+                        bail!("turbomachinery.{} stage {} pressure_ratio must be > 1", kind, i + 1);
+                    }
+                    if !(0.0..=1.0).contains(&s.polytropic_eff) {
+                        bail!("turbomachinery.{} stage {} polytropic_eff must be in [0, 1]", kind, i + 1);
+                    }
+                }
+            }
+            if tm.lp_compressor_stages >= tm.compressor.len() {
+                bail!("turbomachinery.lp_compressor_stages must be < the number of compressor stages");
+            }
+        }
+
+        // v2.29: Validate optimisation objectives if present
+        if let Some(ref objs) = self.objectives {
+            if objs.is_empty() {
+                bail!("objectives must declare at least one metric");
+            }
+            let valid_metrics = ["tsfc", "thrust", "t4", "fuel_burn"];
+            for o in objs {
+                if !valid_metrics.contains(&o.metric.as_str()) {
+                    bail!("objective metric '{}' must be tsfc, thrust, t4, or fuel_burn", o.metric);
+                }
+                if !["min", "max"].contains(&o.sense()) {
+                    bail!("objective '{}' sense must be 'min' or 'max'", o.metric);
+                }
+            }
+        }
+
+        // v2.19: Validate uncertainty if present
+        if let Some(ref uq) = self.uncertainty {
+            if uq.samples == 0 {
+                bail!("uncertainty.samples must be >= 1");
+            }
+            if uq.parameters.is_empty() {
+                bail!("uncertainty.parameters must declare at least one distribution");
+            }
+            let valid_names = [
+                "eta_comp", "eta_turb", "bpr", "opr",
+                "losses.inlet", "losses.burner", "losses.turbine", "losses.nozzle",
+            ];
+            for p in &uq.parameters {
+                if !valid_names.contains(&p.name.as_str()) {
+                    bail!("uncertainty parameter '{}' is not an uncertain input", p.name);
+                }
+                match p.dist.as_str() {
+                    "normal" => {
+                        if p.mean.is_none() || p.std.is_none() {
+                            bail!("normal distribution on '{}' requires mean and std", p.name);
+                        }
+                        if p.std.unwrap() <= 0.0 {
+                            bail!("uncertainty parameter '{}' std must be positive", p.name);
+                        }
+                    }
+                    "uniform" => {
+                        match (p.min, p.max) {
+                            (Some(lo), Some(hi)) if lo < hi => {}
+                            _ => bail!("uniform distribution on '{}' requires min < max", p.name),
+                        }
+                    }
+                    other => bail!("uncertainty parameter '{}' has unknown dist '{}'", p.name, other),
+                }
+            }
+        }
+
         Ok(())
     }
 }
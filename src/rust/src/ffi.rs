@@ -10,6 +10,14 @@ pub const SOLVER_VERSION: &str = "1.1.0";
 pub const MODEL_STANDARD: i32 = 0;
 pub const MODEL_ADVANCED: i32 = 1;
 
+// Globalization mode for the Newton step (v2.10)
+pub const GLOBALIZE_DAMPED: i32 = 0; // Fixed-damping Newton (default, backward compatible)
+pub const GLOBALIZE_LM: i32 = 1;     // Trust-region Levenberg–Marquardt
+
+// T4 limiter mode (v2.41)
+pub const T4_LIMIT_OFF: i32 = 0;     // Flag CONSTRAINT_VIOL on T4 overshoot (legacy behavior)
+pub const MODE_T4_LIMITED: i32 = 1;  // Throttle fuel so T4 rides t4_max via Fischer-Burmeister complementarity
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 pub struct MinotaurInput {
@@ -27,6 +35,43 @@ pub struct MinotaurInput {
     pub mass_tol: c_double,
     pub energy_tol: c_double,
     pub t4_max: c_double,
+    // Globalization mode (v2.10): 0=damped Newton, 1=Levenberg–Marquardt
+    pub globalize: c_int,
+    pub lm_lambda_init: c_double,   // Initial LM damping parameter
+    pub lm_lambda_up: c_double,     // Growth factor on rejected steps (e.g. 10)
+    pub lm_lambda_down: c_double,   // Shrink factor on accepted steps (e.g. 10)
+    pub lm_lambda_cap: c_double,    // Divergence cap; abort if lambda exceeds it
+    // Quasi-Newton Jacobian reuse (v2.11): 0=fresh Jacobian each step, 1=Broyden/Klement updates
+    pub jac_reuse: c_int,
+    pub jac_refresh_interval: c_int, // Force a true Jacobian every N iterations (0 = never)
+    pub jac_stagnation_factor: c_double, // Refresh when the residual fails to drop by this factor
+    // Termination condition (v2.12): 0=abs, 1=rel, 2=step, 3=safe
+    pub term_mode: c_int,
+    pub abstol: c_double,   // Absolute residual tolerance
+    pub reltol: c_double,   // Relative residual tolerance
+    pub stol: c_double,     // Step-size tolerance
+    // Warm-start initial guess (v2.21): seed the Newton iteration from a
+    // previously converged neighbour instead of the cold default. Ignored
+    // unless warm_start is 1, so cold solves remain bit-for-bit unchanged.
+    pub warm_start: c_int,  // 1 = use the warm_* fields as the initial guess
+    pub warm_bpr: c_double, // Starting bypass-ratio working state
+    pub warm_opr: c_double, // Starting overall-pressure-ratio working state
+    pub warm_t4: c_double,  // Starting turbine-inlet temperature
+    // State floors (v2.27): multiplicative lower-limit factors applied to the
+    // intermediate state each Newton iteration, clamping each quantity to
+    // `factor * reference` so it can never go non-positive or collapse toward
+    // zero. A factor of 0 disables the floor for that quantity.
+    pub floor_pressure: c_double, // Floor factor on station total pressures
+    pub floor_temp: c_double,     // Floor factor on station total temperatures
+    pub floor_massfrac: c_double, // Floor factor on mass fractions
+    // Multi-stage / multi-spool turbomachinery (v2.28): the Rust side rolls the
+    // stage stack up into the derived opr/eta_comp/eta_turb above; these counts
+    // let the core distribute the aggregate work across the declared stages and
+    // spools. `n_comp_stages`/`n_turb_stages` of 0 keeps the single lumped
+    // behaviour unchanged.
+    pub n_comp_stages: c_int,  // Number of compressor stages (0 = lumped)
+    pub n_turb_stages: c_int,  // Number of turbine stages (0 = lumped)
+    pub lp_comp_stages: c_int, // Compressor stages on the LP spool (0 = single-spool)
 }
 
 // Extended input with component models and degradation (v2.4)
@@ -48,6 +93,34 @@ pub struct MinotaurInputExt {
     pub mass_tol: c_double,
     pub energy_tol: c_double,
     pub t4_max: c_double,
+    // Globalization mode (v2.10): 0=damped Newton, 1=Levenberg–Marquardt
+    pub globalize: c_int,
+    pub lm_lambda_init: c_double,
+    pub lm_lambda_up: c_double,
+    pub lm_lambda_down: c_double,
+    pub lm_lambda_cap: c_double,
+    // Quasi-Newton Jacobian reuse (v2.11)
+    pub jac_reuse: c_int,
+    pub jac_refresh_interval: c_int,
+    pub jac_stagnation_factor: c_double,
+    // Termination condition (v2.12): 0=abs, 1=rel, 2=step, 3=safe
+    pub term_mode: c_int,
+    pub abstol: c_double,
+    pub reltol: c_double,
+    pub stol: c_double,
+    // Warm-start initial guess (v2.21)
+    pub warm_start: c_int,
+    pub warm_bpr: c_double,
+    pub warm_opr: c_double,
+    pub warm_t4: c_double,
+    // State floors (v2.27): see MinotaurInput
+    pub floor_pressure: c_double,
+    pub floor_temp: c_double,
+    pub floor_massfrac: c_double,
+    // Multi-stage / multi-spool turbomachinery (v2.28): see MinotaurInput
+    pub n_comp_stages: c_int,
+    pub n_turb_stages: c_int,
+    pub lp_comp_stages: c_int,
     // Component model selection
     pub compressor_model: c_int,    // 0=standard, 1=advanced
     pub turbine_model: c_int,       // 0=standard, 1=advanced
@@ -62,6 +135,15 @@ pub struct MinotaurInputExt {
     pub eta_turb_factor: c_double,  // Turbine efficiency multiplier
     pub loss_adder: c_double,       // Additional pressure loss
     pub is_degraded: c_int,         // 1 if degradation scenario, 0 otherwise
+    // Gas model (v2.15): 0=calorically perfect, 1=NASA-poly air, 2=NASA-poly combustion
+    pub gas_model: c_int,
+    // T4 limiter mode (v2.41): T4_LIMIT_OFF=flag CONSTRAINT_VIOL on overshoot
+    // (legacy behavior), MODE_T4_LIMITED=throttle fuel via the
+    // Fischer-Burmeister complementarity equation so T4 rides the limit.
+    pub t4_limit_mode: c_int,
+    // Multi-species fuel blend (v2.42): 0=scalar fuel_k (default), 1=use the
+    // MinotaurFuelSpec passed separately to solve_fuel/minotaur_solve_fuel_c.
+    pub fuel_spec_enabled: c_int,
 }
 
 impl MinotaurInputExt {
@@ -82,6 +164,31 @@ impl MinotaurInputExt {
             mass_tol: inp.mass_tol,
             energy_tol: inp.energy_tol,
             t4_max: inp.t4_max,
+            globalize: inp.globalize,
+            lm_lambda_init: inp.lm_lambda_init,
+            lm_lambda_up: inp.lm_lambda_up,
+            lm_lambda_down: inp.lm_lambda_down,
+            lm_lambda_cap: inp.lm_lambda_cap,
+            jac_reuse: inp.jac_reuse,
+            jac_refresh_interval: inp.jac_refresh_interval,
+            jac_stagnation_factor: inp.jac_stagnation_factor,
+            term_mode: inp.term_mode,
+            abstol: inp.abstol,
+            reltol: inp.reltol,
+            stol: inp.stol,
+            // Carry the warm-start guess through to the extended solver
+            warm_start: inp.warm_start,
+            warm_bpr: inp.warm_bpr,
+            warm_opr: inp.warm_opr,
+            warm_t4: inp.warm_t4,
+            // Carry the state floors through to the extended solver
+            floor_pressure: inp.floor_pressure,
+            floor_temp: inp.floor_temp,
+            floor_massfrac: inp.floor_massfrac,
+            // Carry the turbomachinery stage stack through to the extended solver
+            n_comp_stages: inp.n_comp_stages,
+            n_turb_stages: inp.n_turb_stages,
+            lp_comp_stages: inp.lp_comp_stages,
             // Default component models (standard)
             compressor_model: MODEL_STANDARD,
             turbine_model: MODEL_STANDARD,
@@ -96,6 +203,12 @@ impl MinotaurInputExt {
             eta_turb_factor: 1.0,
             loss_adder: 0.0,
             is_degraded: 0,
+            // Calorically-perfect gas by default
+            gas_model: 0,
+            // T4 limiter off by default (legacy CONSTRAINT_VIOL behavior)
+            t4_limit_mode: T4_LIMIT_OFF,
+            // Scalar fuel_k by default; no fuel spec selected
+            fuel_spec_enabled: 0,
         }
     }
 
@@ -127,6 +240,22 @@ impl MinotaurInputExt {
         updated.nozzle_model = nozzle;
         updated
     }
+
+    /// Switch between flagging T4 overshoot as `CONSTRAINT_VIOL` ([`T4_LIMIT_OFF`])
+    /// and the semismooth-Newton fuel-throttle formulation ([`MODE_T4_LIMITED`]).
+    pub fn with_t4_limit_mode(&self, mode: i32) -> Self {
+        let mut updated = *self;
+        updated.t4_limit_mode = mode;
+        updated
+    }
+
+    /// Select the multi-species [`MinotaurFuelSpec`] blend (v2.42) in place of
+    /// the scalar `fuel_k`; pass the spec itself to [`solve_fuel`] separately.
+    pub fn with_fuel_spec_enabled(&self, enabled: bool) -> Self {
+        let mut updated = *self;
+        updated.fuel_spec_enabled = enabled as c_int;
+        updated
+    }
 }
 
 #[repr(C)]
@@ -141,6 +270,11 @@ pub struct MinotaurOutput {
     pub thrust_proxy: c_double,
     pub final_bpr: c_double,
     pub final_residual: c_double,
+    pub final_lambda: c_double, // v2.10: final LM damping parameter (0 in damped-Newton mode)
+    pub jac_true_evals: c_int,  // v2.11: number of true-Jacobian factorizations
+    pub jac_updates: c_int,     // v2.11: number of rank-1 quasi-Newton updates
+    pub clamp_iters: c_int,     // v2.27: iterations in which a state floor clamped a quantity
+    pub fuel_throttle_q: c_double, // v2.41: converged throttle control q under MODE_T4_LIMITED (0 if the limit wasn't engaged)
 }
 
 #[repr(C)]
@@ -223,6 +357,41 @@ pub struct JacobianResult {
     pub output_names: [&'static str; 3],
 }
 
+// Hessian result via forward-over-forward AD (v2.14)
+//
+// The cycle equations are propagated through a "Jet" dual number carrying a
+// value, a first-order gradient, and a symmetric second-derivative block. The
+// seed for parameter `i` sets its first-order part to 1 and all others to 0;
+// products propagate `d(fg) = f·dg + g·df` and
+// `d²(fg) = f·d²g + 2·df·dg + g·d²f`, with sqrt/pow/exp/log handled by their
+// analytic second derivatives.
+#[derive(Clone, Debug)]
+pub struct HessianResult {
+    pub jacobian: [[f64; 3]; 6],        // 6 params x 3 outputs (first derivatives)
+    pub hessian: [[[f64; 6]; 6]; 3],    // per-output symmetric 6x6 Hessian block
+    pub base_tsfc: f64,
+    pub base_thrust: f64,
+    pub base_t4: f64,
+    pub status: i32,
+    pub param_names: [&'static str; 6],
+    pub output_names: [&'static str; 3],
+}
+
+impl HessianResult {
+    pub fn new() -> Self {
+        Self {
+            jacobian: [[0.0; 3]; 6],
+            hessian: [[[0.0; 6]; 6]; 3],
+            base_tsfc: 0.0,
+            base_thrust: 0.0,
+            base_t4: 0.0,
+            status: 0,
+            param_names: ["mach", "alt_km", "bpr", "opr", "eta_comp", "eta_turb"],
+            output_names: ["tsfc", "thrust", "t4"],
+        }
+    }
+}
+
 impl JacobianResult {
     pub fn new() -> Self {
         Self {
@@ -240,6 +409,14 @@ impl JacobianResult {
 extern "C" {
     pub fn minotaur_solve_c(inp: MinotaurInput, out: *mut MinotaurOutput);
     pub fn minotaur_solve_ext_c(inp_ext: MinotaurInputExt, out: *mut MinotaurOutput);
+
+    // Precision variants (v2.13): identical ABI, differing internal working type.
+    // Guarded by build features so platforms lacking the narrow/wide float
+    // fall back to the f64 path below.
+    #[cfg(feature = "f128")]
+    pub fn minotaur_solve_f128_c(inp: MinotaurInput, out: *mut MinotaurOutput);
+    #[cfg(feature = "f16")]
+    pub fn minotaur_solve_f16_c(inp: MinotaurInput, out: *mut MinotaurOutput);
     pub fn minotaur_get_version(major: *mut c_int, minor: *mut c_int, patch: *mut c_int);
     pub fn minotaur_get_schema_version(major: *mut c_int, minor: *mut c_int, patch: *mut c_int);
 
@@ -261,6 +438,16 @@ extern "C" {
         base_tsfc: *mut c_double, base_thrust: *mut c_double, base_t4: *mut c_double,
         status: *mut c_int
     );
+
+    // Second-order sensitivities via forward-over-forward Jets (v2.14)
+    pub fn minotaur_hessian_c(
+        mach: c_double, alt_km: c_double, bpr: c_double, opr: c_double,
+        eta_comp: c_double, eta_turb: c_double, t4_max: c_double,
+        jacobian: *mut [[c_double; 3]; 6],
+        hessian: *mut [[[c_double; 6]; 6]; 3],
+        base_tsfc: *mut c_double, base_thrust: *mut c_double, base_t4: *mut c_double,
+        status: *mut c_int
+    );
 }
 
 pub fn solve(inp: MinotaurInput) -> MinotaurOutput {
@@ -274,12 +461,119 @@ pub fn solve(inp: MinotaurInput) -> MinotaurOutput {
         thrust_proxy: 0.0,
         final_bpr: 0.0,
         final_residual: 0.0,
+        final_lambda: 0.0,
+        jac_true_evals: 0,
+        jac_updates: 0,
+        clamp_iters: 0,
+        fuel_throttle_q: 0.0,
     };
     unsafe { minotaur_solve_c(inp, &mut out as *mut _) };
     out
 }
 
-/// Extended solve with component models and degradation (v2.4)
+/// Working precision for the cycle solve (v2.13).
+///
+/// The residual iteration and `compute_jacobian` are generic over the float
+/// working type in the Fortran core; these variants pick the instantiation.
+/// `F128` tightens truncation error for stiff near-limit cases, while `F16`
+/// is a fast/low-memory mode for large population evaluations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precision {
+    F64,
+    F128,
+    F16,
+}
+
+/// High-precision (f128) solve, falling back to f64 when the platform or build
+/// lacks quad-precision support.
+pub fn solve_f128(inp: MinotaurInput) -> MinotaurOutput {
+    #[cfg(feature = "f128")]
+    {
+        let mut out = zeroed_output();
+        unsafe { minotaur_solve_f128_c(inp, &mut out as *mut _) };
+        out
+    }
+    #[cfg(not(feature = "f128"))]
+    {
+        solve(inp)
+    }
+}
+
+/// Reduced-precision (f16) solve, falling back to f64 when half-precision is
+/// unavailable.
+pub fn solve_f16(inp: MinotaurInput) -> MinotaurOutput {
+    #[cfg(feature = "f16")]
+    {
+        let mut out = zeroed_output();
+        unsafe { minotaur_solve_f16_c(inp, &mut out as *mut _) };
+        out
+    }
+    #[cfg(not(feature = "f16"))]
+    {
+        solve(inp)
+    }
+}
+
+/// Solve at the requested working precision.
+pub fn solve_precision(inp: MinotaurInput, precision: Precision) -> MinotaurOutput {
+    match precision {
+        Precision::F64 => solve(inp),
+        Precision::F128 => solve_f128(inp),
+        Precision::F16 => solve_f16(inp),
+    }
+}
+
+fn zeroed_output() -> MinotaurOutput {
+    MinotaurOutput {
+        status: -999,
+        iter: 0,
+        mass_resid: 0.0,
+        energy_resid: 0.0,
+        t4: 0.0,
+        tsfc_proxy: 0.0,
+        thrust_proxy: 0.0,
+        final_bpr: 0.0,
+        final_residual: 0.0,
+        final_lambda: 0.0,
+        jac_true_evals: 0,
+        jac_updates: 0,
+        clamp_iters: 0,
+        fuel_throttle_q: 0.0,
+    }
+}
+
+/// Fischer-Burmeister complementarity function for the T4 limiter (v2.41):
+/// `φ(m,q) = m + q - sqrt(m² + q²)`, where `m = t4_max - T4` is the
+/// temperature margin and `q ≥ 0` is the fuel-throttle control variable.
+/// `φ(m,q) = 0` is equivalent to the complementarity condition `m ≥ 0, q ≥ 0,
+/// m·q = 0` the core enforces under [`MODE_T4_LIMITED`]: the throttle stays
+/// at zero while T4 is under the limit, and only engages once the margin
+/// would otherwise go negative.
+pub fn fischer_burmeister(m: f64, q: f64) -> f64 {
+    m + q - (m * m + q * q).sqrt()
+}
+
+/// Partial derivatives of [`fischer_burmeister`] w.r.t. `(m, q)` (v2.41).
+/// `φ` is smooth away from the origin; at `m = q = 0` it returns the
+/// subgradient element `(1 - 1/sqrt(2), 1 - 1/sqrt(2))`, which keeps the
+/// augmented Newton Jacobian nonsingular there.
+pub fn fischer_burmeister_grad(m: f64, q: f64) -> (f64, f64) {
+    let norm = (m * m + q * q).sqrt();
+    if norm == 0.0 {
+        let s = 1.0 - std::f64::consts::FRAC_1_SQRT_2;
+        (s, s)
+    } else {
+        (1.0 - m / norm, 1.0 - q / norm)
+    }
+}
+
+/// Extended solve with component models and degradation (v2.4). Set
+/// `inp_ext.t4_limit_mode` to [`MODE_T4_LIMITED`] (v2.41) to have the core
+/// throttle fuel via the Fischer-Burmeister complementarity equation (see
+/// [`fischer_burmeister`]) instead of flagging `CONSTRAINT_VIOL` on T4
+/// overshoot; the converged throttle is reported in
+/// [`MinotaurOutput::fuel_throttle_q`] and `out.t4` is pinned at `t4_max`
+/// whenever the limit engaged.
 pub fn solve_ext(inp_ext: MinotaurInputExt) -> MinotaurOutput {
     let mut out = MinotaurOutput {
         status: -999,
@@ -291,6 +585,11 @@ pub fn solve_ext(inp_ext: MinotaurInputExt) -> MinotaurOutput {
         thrust_proxy: 0.0,
         final_bpr: 0.0,
         final_residual: 0.0,
+        final_lambda: 0.0,
+        jac_true_evals: 0,
+        jac_updates: 0,
+        clamp_iters: 0,
+        fuel_throttle_q: 0.0,
     };
     unsafe { minotaur_solve_ext_c(inp_ext, &mut out as *mut _) };
     out
@@ -359,6 +658,8 @@ pub fn status_name(code: i32) -> &'static str {
         3 => "INVARIANT_VIOL",
         4 => "CONSTRAINT_VIOL",
         5 => "NONPHYSICAL",
+        6 => "CONVERGED_STEP",       // v2.12: step-size criterion satisfied
+        7 => "STALLED_ACCEPTABLE",   // v2.12: best-so-far accepted under safe termination
         _ => "UNKNOWN",
     }
 }
@@ -435,6 +736,30 @@ pub fn compute_jacobian(
     result
 }
 
+/// Compute per-output Hessians (and the Jacobian) via forward-over-forward AD (v2.14)
+pub fn compute_hessian(
+    mach: f64, alt_km: f64, bpr: f64, opr: f64,
+    eta_comp: f64, eta_turb: f64, t4_max: f64
+) -> HessianResult {
+    let mut result = HessianResult::new();
+    let mut status: c_int = 0;
+
+    unsafe {
+        minotaur_hessian_c(
+            mach, alt_km, bpr, opr, eta_comp, eta_turb, t4_max,
+            &mut result.jacobian,
+            &mut result.hessian,
+            &mut result.base_tsfc,
+            &mut result.base_thrust,
+            &mut result.base_t4,
+            &mut status
+        );
+    }
+
+    result.status = status;
+    result
+}
+
 /// Get seed parameter name (v2.8)
 pub fn seed_param_name(code: i32) -> &'static str {
     match code {
@@ -447,3 +772,386 @@ pub fn seed_param_name(code: i32) -> &'static str {
         _ => "unknown",
     }
 }
+
+//-----------------------------------------------------------------------------
+// Reverse-mode adjoint gradient (v2.39)
+//-----------------------------------------------------------------------------
+
+// Objective selector for the adjoint solve (v2.39)
+pub const OBJECTIVE_TSFC: i32 = 0;
+pub const OBJECTIVE_THRUST: i32 = 1;
+pub const OBJECTIVE_T4: i32 = 2;
+
+/// Gradient of a single scalar objective w.r.t. all six cycle inputs,
+/// computed from one adjoint solve rather than one forward-AD solve per
+/// parameter (v2.39).
+#[derive(Clone, Debug)]
+pub struct AdjointResult {
+    pub objective_val: f64,
+    pub gradient: [f64; 6], // dJ/dp for p = (mach, alt_km, bpr, opr, eta_comp, eta_turb)
+    pub status: i32,
+    pub objective_name: &'static str,
+    pub param_names: [&'static str; 6],
+}
+
+impl AdjointResult {
+    pub fn new(objective: i32) -> Self {
+        Self {
+            objective_val: 0.0,
+            gradient: [0.0; 6],
+            status: 0,
+            objective_name: objective_name(objective),
+            param_names: ["mach", "alt_km", "bpr", "opr", "eta_comp", "eta_turb"],
+        }
+    }
+}
+
+/// Get objective selector name (v2.39)
+pub fn objective_name(code: i32) -> &'static str {
+    match code {
+        OBJECTIVE_TSFC => "tsfc",
+        OBJECTIVE_THRUST => "thrust",
+        OBJECTIVE_T4 => "t4",
+        _ => "unknown",
+    }
+}
+
+extern "C" {
+    // Reverse-mode adjoint (v2.39). The core converges F(u,p)=0 with the
+    // Newton Jacobian ∂F/∂u already in hand, solves the single linear adjoint
+    // system (∂F/∂u)ᵀ λ = ∂J/∂u for the chosen objective J, and returns
+    // dJ/dp = ∂J/∂p − λᵀ(∂F/∂p) — one solve regardless of parameter count,
+    // versus the six forward-AD solves `compute_jacobian` needs per output.
+    pub fn minotaur_solve_adjoint_c(
+        mach: c_double, alt_km: c_double, bpr: c_double, opr: c_double,
+        eta_comp: c_double, eta_turb: c_double, t4_max: c_double,
+        objective_selector: c_int,
+        objective_val: *mut c_double,
+        gradient: *mut [c_double; 6],
+        status: *mut c_int
+    );
+}
+
+/// Gradient of `objective` (one of [`OBJECTIVE_TSFC`], [`OBJECTIVE_THRUST`],
+/// [`OBJECTIVE_T4`]) w.r.t. all six cycle inputs via a single adjoint solve
+/// (v2.39). Prefer this over [`compute_jacobian`] when only one output's
+/// gradient over many inputs is needed, e.g. driving gradient-based cycle
+/// optimization over dozens of loss/degradation parameters.
+pub fn solve_adjoint(
+    mach: f64, alt_km: f64, bpr: f64, opr: f64,
+    eta_comp: f64, eta_turb: f64, t4_max: f64,
+    objective: i32,
+) -> AdjointResult {
+    let mut result = AdjointResult::new(objective);
+    let mut status: c_int = 0;
+
+    unsafe {
+        minotaur_solve_adjoint_c(
+            mach, alt_km, bpr, opr, eta_comp, eta_turb, t4_max,
+            objective,
+            &mut result.objective_val,
+            &mut result.gradient,
+            &mut status,
+        );
+    }
+
+    result.status = status;
+    result
+}
+
+//-----------------------------------------------------------------------------
+// Batched structure-of-arrays solve with optional GPU offload (v2.40)
+//-----------------------------------------------------------------------------
+
+// Batch execution backend selector (v2.40)
+pub const BATCH_BACKEND_CPU: i32 = 0;
+pub const BATCH_BACKEND_GPU: i32 = 1;
+
+// Below this many points, the launch overhead of the SoA/GPU kernel isn't
+// worth paying, so `solve_batch` just loops `solve_ext` (v2.40).
+pub const BATCH_SERIAL_THRESHOLD: usize = 8;
+
+/// Structure-of-arrays view over `n` independent solve points for the GPU
+/// batch kernel (v2.40). Only the core cycle parameters are exposed here —
+/// a field-per-array layout lets the kernel coalesce memory across points,
+/// but every point must share the same (default) solver configuration, since
+/// threading all of [`MinotaurInputExt`]'s globalization/warm-start/floor
+/// fields through per-point arrays would give the kernel no more than
+/// `solve_ext` already offers serially. `solve_batch` checks this
+/// precondition and falls back to the CPU path otherwise.
+#[repr(C)]
+pub struct MinotaurInputSoA {
+    pub n: usize,
+    pub mach: *const c_double,
+    pub alt_km: *const c_double,
+    pub bpr: *const c_double,
+    pub opr: *const c_double,
+    pub eta_comp: *const c_double,
+    pub eta_turb: *const c_double,
+    pub eta_nozz: *const c_double,
+    pub fuel_k: *const c_double,
+    pub t4_max: *const c_double,
+}
+
+/// Structure-of-arrays output counterpart to [`MinotaurInputSoA`] (v2.40).
+#[repr(C)]
+pub struct MinotaurOutputSoA {
+    pub status: *mut c_int,
+    pub mass_resid: *mut c_double,
+    pub energy_resid: *mut c_double,
+    pub t4: *mut c_double,
+    pub tsfc_proxy: *mut c_double,
+    pub thrust_proxy: *mut c_double,
+}
+
+extern "C" {
+    // One launch solves all `n` points independently (v2.40); `backend`
+    // selects `BATCH_BACKEND_CPU`/`BATCH_BACKEND_GPU` on the native side.
+    pub fn minotaur_solve_batch_c(inp: *const MinotaurInputSoA, backend: c_int, out: *mut MinotaurOutputSoA);
+
+    // Single-point solve that also reports convergence history (v2.40);
+    // `solve_batch_with_diagnostics` calls this once per point.
+    pub fn minotaur_solve_ext_diag_c(
+        inp_ext: MinotaurInputExt,
+        out: *mut MinotaurOutput,
+        diag: *mut MinotaurDiagnostics,
+    );
+}
+
+/// True when `inp` uses only the defaults the GPU SoA kernel supports: damped
+/// Newton, a fresh Jacobian each step, absolute termination, no warm start,
+/// no state floors, the standard component models, no degradation, and the
+/// T4 limiter off (v2.40, extended v2.41). `solve_batch` routes anything else
+/// through the CPU path.
+fn is_gpu_batch_eligible(inp: &MinotaurInputExt) -> bool {
+    inp.globalize == GLOBALIZE_DAMPED
+        && inp.jac_reuse == 0
+        && inp.term_mode == 0
+        && inp.warm_start == 0
+        && inp.floor_pressure == 0.0
+        && inp.floor_temp == 0.0
+        && inp.floor_massfrac == 0.0
+        && inp.compressor_model == MODEL_STANDARD
+        && inp.turbine_model == MODEL_STANDARD
+        && inp.nozzle_model == MODEL_STANDARD
+        && inp.is_degraded == 0
+        && inp.t4_limit_mode == T4_LIMIT_OFF
+}
+
+/// Solve every point in `inputs` independently and return one [`MinotaurOutput`]
+/// per point, in the same order (v2.40). Batches below [`BATCH_SERIAL_THRESHOLD`],
+/// a `backend` other than [`BATCH_BACKEND_GPU`], or any point whose solver
+/// configuration isn't GPU-eligible (see [`is_gpu_batch_eligible`]) run through
+/// `solve_ext` via `parallel::par_map` using `threads` worker threads; large,
+/// fully-eligible GPU batches route through the coalesced
+/// `minotaur_solve_batch_c` kernel in one launch instead.
+pub fn solve_batch(inputs: &[MinotaurInputExt], threads: usize, backend: i32) -> Vec<MinotaurOutput> {
+    if inputs.len() < BATCH_SERIAL_THRESHOLD
+        || backend != BATCH_BACKEND_GPU
+        || !inputs.iter().all(is_gpu_batch_eligible)
+    {
+        return crate::parallel::par_map(inputs, threads, |_, &inp| solve_ext(inp));
+    }
+
+    let n = inputs.len();
+    let mach: Vec<c_double> = inputs.iter().map(|i| i.mach).collect();
+    let alt_km: Vec<c_double> = inputs.iter().map(|i| i.alt_km).collect();
+    let bpr: Vec<c_double> = inputs.iter().map(|i| i.bpr).collect();
+    let opr: Vec<c_double> = inputs.iter().map(|i| i.opr).collect();
+    let eta_comp: Vec<c_double> = inputs.iter().map(|i| i.eta_comp).collect();
+    let eta_turb: Vec<c_double> = inputs.iter().map(|i| i.eta_turb).collect();
+    let eta_nozz: Vec<c_double> = inputs.iter().map(|i| i.eta_nozz).collect();
+    let fuel_k: Vec<c_double> = inputs.iter().map(|i| i.fuel_k).collect();
+    let t4_max: Vec<c_double> = inputs.iter().map(|i| i.t4_max).collect();
+
+    let inp_soa = MinotaurInputSoA {
+        n,
+        mach: mach.as_ptr(),
+        alt_km: alt_km.as_ptr(),
+        bpr: bpr.as_ptr(),
+        opr: opr.as_ptr(),
+        eta_comp: eta_comp.as_ptr(),
+        eta_turb: eta_turb.as_ptr(),
+        eta_nozz: eta_nozz.as_ptr(),
+        fuel_k: fuel_k.as_ptr(),
+        t4_max: t4_max.as_ptr(),
+    };
+
+    let mut status = vec![0 as c_int; n];
+    let mut mass_resid = vec![0.0; n];
+    let mut energy_resid = vec![0.0; n];
+    let mut t4 = vec![0.0; n];
+    let mut tsfc_proxy = vec![0.0; n];
+    let mut thrust_proxy = vec![0.0; n];
+
+    let mut out_soa = MinotaurOutputSoA {
+        status: status.as_mut_ptr(),
+        mass_resid: mass_resid.as_mut_ptr(),
+        energy_resid: energy_resid.as_mut_ptr(),
+        t4: t4.as_mut_ptr(),
+        tsfc_proxy: tsfc_proxy.as_mut_ptr(),
+        thrust_proxy: thrust_proxy.as_mut_ptr(),
+    };
+
+    unsafe { minotaur_solve_batch_c(&inp_soa, backend, &mut out_soa) };
+
+    (0..n)
+        .map(|i| MinotaurOutput {
+            status: status[i],
+            iter: 0,
+            mass_resid: mass_resid[i],
+            energy_resid: energy_resid[i],
+            t4: t4[i],
+            tsfc_proxy: tsfc_proxy[i],
+            thrust_proxy: thrust_proxy[i],
+            final_bpr: bpr[i],
+            final_residual: mass_resid[i].max(energy_resid[i]),
+            final_lambda: 0.0,
+            jac_true_evals: 0,
+            jac_updates: 0,
+            clamp_iters: 0,
+            fuel_throttle_q: 0.0,
+        })
+        .collect()
+}
+
+/// Like [`solve_batch`] but preserves each point's convergence history
+/// (v2.40). Always runs the CPU path: the SoA kernel only reports converged
+/// state, not the Newton history, so there is no GPU fast path here.
+pub fn solve_batch_with_diagnostics(
+    inputs: &[MinotaurInputExt],
+    threads: usize,
+) -> Vec<(MinotaurOutput, MinotaurDiagnostics)> {
+    crate::parallel::par_map(inputs, threads, |_, &inp| {
+        let mut out = MinotaurOutput {
+            status: -999,
+            iter: 0,
+            mass_resid: 0.0,
+            energy_resid: 0.0,
+            t4: 0.0,
+            tsfc_proxy: 0.0,
+            thrust_proxy: 0.0,
+            final_bpr: 0.0,
+            final_residual: 0.0,
+            final_lambda: 0.0,
+            jac_true_evals: 0,
+            jac_updates: 0,
+            clamp_iters: 0,
+            fuel_throttle_q: 0.0,
+        };
+        let mut diag = MinotaurDiagnostics::default();
+        unsafe { minotaur_solve_ext_diag_c(inp, &mut out as *mut _, &mut diag as *mut _) };
+        (out, diag)
+    })
+}
+
+//-----------------------------------------------------------------------------
+// Composition-based multi-species fuel model (v2.42)
+//-----------------------------------------------------------------------------
+
+// Largest blend `MinotaurFuelSpec` can describe; `n_species` selects how many
+// of the fixed-size arrays below are actually populated.
+pub const FUEL_SPEC_MAX_SPECIES: usize = 4;
+
+/// Multi-species fuel blend (v2.42): `species_frac` are mass fractions of the
+/// mix (summing to 1), `lhv` is each species' lower heating value (MJ/kg),
+/// and `stoich_afr` is each species' stoichiometric air-fuel ratio. This
+/// replaces the opaque scalar `fuel_k` so blends like Jet-A/SAF mixes or
+/// hydrogen fractions shift T4 and TSFC the way their composition implies.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MinotaurFuelSpec {
+    pub n_species: c_int,
+    pub species_frac: [c_double; FUEL_SPEC_MAX_SPECIES],
+    pub lhv: [c_double; FUEL_SPEC_MAX_SPECIES],
+    pub stoich_afr: [c_double; FUEL_SPEC_MAX_SPECIES],
+}
+
+impl MinotaurFuelSpec {
+    /// An empty blend (`n_species = 0`); fill in `species_frac`/`lhv`/`stoich_afr`
+    /// for the first `n` entries and set `n_species = n` before solving.
+    pub fn empty() -> Self {
+        Self {
+            n_species: 0,
+            species_frac: [0.0; FUEL_SPEC_MAX_SPECIES],
+            lhv: [0.0; FUEL_SPEC_MAX_SPECIES],
+            stoich_afr: [0.0; FUEL_SPEC_MAX_SPECIES],
+        }
+    }
+}
+
+/// Effective lower heating value, effective stoichiometric AFR, and a
+/// validity flag for a [`MinotaurFuelSpec`] (v2.42). `lhv_mj_kg` is the
+/// mass-fraction-weighted mix LHV = Σ wᵢ·LHVᵢ; `stoich_afr` is the
+/// mass-fraction-weighted oxygen demand Σ wᵢ·AFRᵢ. `valid` is false when the
+/// fractions don't sum to 1 within tolerance, in which case the two values
+/// above should not be trusted.
+#[derive(Clone, Copy, Debug)]
+pub struct FuelMixSummary {
+    pub lhv_mj_kg: f64,
+    pub stoich_afr: f64,
+    pub valid: bool,
+}
+
+// Tolerance for `species_frac` summing to 1 in `fuel_mix_summary`.
+const FUEL_FRAC_SUM_TOL: f64 = 1e-6;
+
+/// Compute the effective LHV/AFR for `spec` and flag whether its mass
+/// fractions are a valid composition (v2.42).
+pub fn fuel_mix_summary(spec: &MinotaurFuelSpec) -> FuelMixSummary {
+    let n = (spec.n_species.max(0) as usize).min(FUEL_SPEC_MAX_SPECIES);
+    let frac_sum: f64 = spec.species_frac[..n].iter().sum();
+    let lhv_mj_kg: f64 = spec.species_frac[..n]
+        .iter()
+        .zip(spec.lhv[..n].iter())
+        .map(|(w, l)| w * l)
+        .sum();
+    let stoich_afr: f64 = spec.species_frac[..n]
+        .iter()
+        .zip(spec.stoich_afr[..n].iter())
+        .map(|(w, afr)| w * afr)
+        .sum();
+
+    FuelMixSummary {
+        lhv_mj_kg,
+        stoich_afr,
+        valid: n > 0 && (frac_sum - 1.0).abs() < FUEL_FRAC_SUM_TOL,
+    }
+}
+
+extern "C" {
+    // Extended solve driven by a multi-species fuel blend instead of the
+    // scalar `fuel_k` (v2.42); `inp_ext.fuel_k` is ignored when
+    // `inp_ext.fuel_spec_enabled` is 1.
+    pub fn minotaur_solve_fuel_c(
+        inp_ext: MinotaurInputExt,
+        spec: MinotaurFuelSpec,
+        out: *mut MinotaurOutput,
+    );
+}
+
+/// Solve with a multi-species fuel blend (v2.42). Set
+/// `inp_ext.fuel_spec_enabled = 1` first (or call this directly regardless —
+/// the core always applies `spec` here); the effective mix LHV/AFR from
+/// [`fuel_mix_summary`] replace `fuel_k` in the energy balance, so T4 and
+/// TSFC respond to blend composition.
+pub fn solve_fuel(inp_ext: MinotaurInputExt, spec: MinotaurFuelSpec) -> MinotaurOutput {
+    let mut out = MinotaurOutput {
+        status: -999,
+        iter: 0,
+        mass_resid: 0.0,
+        energy_resid: 0.0,
+        t4: 0.0,
+        tsfc_proxy: 0.0,
+        thrust_proxy: 0.0,
+        final_bpr: 0.0,
+        final_residual: 0.0,
+        final_lambda: 0.0,
+        jac_true_evals: 0,
+        jac_updates: 0,
+        clamp_iters: 0,
+        fuel_throttle_q: 0.0,
+    };
+    unsafe { minotaur_solve_fuel_c(inp_ext, spec, &mut out as *mut _) };
+    out
+}
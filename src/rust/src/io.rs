@@ -3,6 +3,52 @@ use anyhow::Result;
 use std::fs::File;
 use std::io::{BufWriter, Write};
 
+/// A single result row in the canonical sweep/single/optimize schema.
+///
+/// Both the [`CsvWriter`] and the columnar [`ArrowWriter`] consume these, so
+/// the two backends always emit the same columns from the same call sites.
+#[derive(Clone, Debug)]
+pub struct ResultRow {
+    pub case: String,
+    pub bpr: f64,
+    pub opr: f64,
+    pub mach: f64,
+    pub alt_km: f64,
+    pub status: i32,
+    pub converged: bool,
+    pub iter: i32,
+    pub mass_resid: f64,
+    pub energy_resid: f64,
+    pub final_residual: f64,
+    pub final_bpr: f64,
+    pub t4: f64,
+    pub tsfc_proxy: f64,
+    pub thrust_proxy: f64,
+}
+
+impl ResultRow {
+    /// Build a row from a solver output and its grid coordinates.
+    pub fn from_output(case: &str, bpr: f64, opr: f64, mach: f64, alt_km: f64, out: &MinotaurOutput) -> Self {
+        Self {
+            case: case.to_string(),
+            bpr,
+            opr,
+            mach,
+            alt_km,
+            status: out.status,
+            converged: out.status == 0,
+            iter: out.iter,
+            mass_resid: out.mass_resid,
+            energy_resid: out.energy_resid,
+            final_residual: out.final_residual,
+            final_bpr: out.final_bpr,
+            t4: out.t4,
+            tsfc_proxy: out.tsfc_proxy,
+            thrust_proxy: out.thrust_proxy,
+        }
+    }
+}
+
 pub struct CsvWriter {
     w: BufWriter<File>,
 }
@@ -13,6 +59,14 @@ impl CsvWriter {
         Ok(Self { w: BufWriter::new(f) })
     }
 
+    /// Open an existing CSV for appending, preserving prior rows. Used by
+    /// resumable sweeps so a continued run keeps a single consistent output
+    /// without rewriting the header.
+    pub fn open_append(path: &str) -> Result<Self> {
+        let f = std::fs::OpenOptions::new().append(true).open(path)?;
+        Ok(Self { w: BufWriter::new(f) })
+    }
+
     pub fn write_header(&mut self) -> Result<()> {
         writeln!(
             self.w,
@@ -53,8 +107,250 @@ impl CsvWriter {
         Ok(())
     }
 
+    /// Header for the optimization generation-history layout, distinct from the
+    /// per-case sweep schema. Pairs with [`write_generation_row`](Self::write_generation_row).
+    pub fn write_generation_header(&mut self) -> Result<()> {
+        writeln!(
+            self.w,
+            "generation,hypervolume,front_size,best_tsfc,best_thrust,mean_cv"
+        )?;
+        Ok(())
+    }
+
+    /// One per-generation trajectory row: the hypervolume convergence metric,
+    /// the current front size, the best TSFC/thrust seen, and the mean
+    /// constraint violation across the population.
+    pub fn write_generation_row(
+        &mut self,
+        gen: usize,
+        hypervolume: f64,
+        front_size: usize,
+        best_tsfc: f64,
+        best_thrust: f64,
+        mean_cv: f64,
+    ) -> Result<()> {
+        writeln!(
+            self.w,
+            "{},{:.6},{},{:.6},{:.6},{:.6e}",
+            gen, hypervolume, front_size, best_tsfc, best_thrust, mean_cv
+        )?;
+        Ok(())
+    }
+
     pub fn flush(&mut self) -> Result<()> {
         self.w.flush()?;
         Ok(())
     }
 }
+
+/// Columnar output backend writing the canonical result schema as a typed
+/// Arrow `RecordBatch`, optionally flushed to a Parquet file.
+///
+/// Rows are buffered column-wise and materialized on [`finish`](ArrowWriter::finish);
+/// the [`Manifest`](crate::Manifest)-derived key/value pairs are embedded as
+/// Parquet file metadata so a result file is self-describing and loadable into
+/// DataFrame/analytics tools without a CSV reparse. Requires the `arrow`
+/// feature; without it the constructor returns a clear error so `--format
+/// parquet` degrades gracefully on a minimal build.
+pub struct ArrowWriter {
+    rows: Vec<ResultRow>,
+    metadata: Vec<(String, String)>,
+}
+
+impl ArrowWriter {
+    /// Create an in-memory columnar writer. `metadata` is embedded as Parquet
+    /// key/value file metadata on [`finish`](ArrowWriter::finish).
+    #[cfg(feature = "arrow")]
+    pub fn new(metadata: Vec<(String, String)>) -> Result<Self> {
+        Ok(Self { rows: Vec::new(), metadata })
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    pub fn new(_metadata: Vec<(String, String)>) -> Result<Self> {
+        anyhow::bail!("parquet/arrow output requires building with the 'arrow' feature")
+    }
+
+    /// Buffer one row.
+    pub fn push(&mut self, row: ResultRow) {
+        self.rows.push(row);
+    }
+
+    /// Build the typed Arrow schema for the canonical result row.
+    #[cfg(feature = "arrow")]
+    fn schema() -> std::sync::Arc<arrow::datatypes::Schema> {
+        use arrow::datatypes::{DataType, Field, Schema};
+        std::sync::Arc::new(Schema::new(vec![
+            Field::new("case", DataType::Utf8, false),
+            Field::new("bpr", DataType::Float64, false),
+            Field::new("opr", DataType::Float64, false),
+            Field::new("mach", DataType::Float64, false),
+            Field::new("alt_km", DataType::Float64, false),
+            Field::new("status", DataType::Int32, false),
+            Field::new("converged", DataType::Boolean, false),
+            Field::new("iter", DataType::Int32, false),
+            Field::new("mass_resid", DataType::Float64, false),
+            Field::new("energy_resid", DataType::Float64, false),
+            Field::new("final_residual", DataType::Float64, false),
+            Field::new("final_bpr", DataType::Float64, false),
+            Field::new("t4", DataType::Float64, false),
+            Field::new("tsfc_proxy", DataType::Float64, false),
+            Field::new("thrust_proxy", DataType::Float64, false),
+        ]))
+    }
+
+    /// Assemble the buffered rows into a single `RecordBatch`.
+    #[cfg(feature = "arrow")]
+    pub fn to_batch(&self) -> Result<arrow::record_batch::RecordBatch> {
+        use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int32Array, StringArray};
+        use std::sync::Arc;
+
+        let case: ArrayRef = Arc::new(StringArray::from(
+            self.rows.iter().map(|r| r.case.clone()).collect::<Vec<_>>(),
+        ));
+        let f64col = |sel: fn(&ResultRow) -> f64| -> ArrayRef {
+            Arc::new(Float64Array::from(self.rows.iter().map(sel).collect::<Vec<_>>()))
+        };
+        let columns: Vec<ArrayRef> = vec![
+            case,
+            f64col(|r| r.bpr),
+            f64col(|r| r.opr),
+            f64col(|r| r.mach),
+            f64col(|r| r.alt_km),
+            Arc::new(Int32Array::from(self.rows.iter().map(|r| r.status).collect::<Vec<_>>())),
+            Arc::new(BooleanArray::from(self.rows.iter().map(|r| r.converged).collect::<Vec<_>>())),
+            Arc::new(Int32Array::from(self.rows.iter().map(|r| r.iter).collect::<Vec<_>>())),
+            f64col(|r| r.mass_resid),
+            f64col(|r| r.energy_resid),
+            f64col(|r| r.final_residual),
+            f64col(|r| r.final_bpr),
+            f64col(|r| r.t4),
+            f64col(|r| r.tsfc_proxy),
+            f64col(|r| r.thrust_proxy),
+        ];
+        Ok(arrow::record_batch::RecordBatch::try_new(Self::schema(), columns)?)
+    }
+
+    /// Write the buffered rows to `path` as Parquet, embedding the manifest
+    /// metadata as file-level key/value pairs.
+    #[cfg(feature = "arrow")]
+    pub fn finish(self, path: &str) -> Result<()> {
+        use parquet::arrow::ArrowWriter as ParquetArrowWriter;
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::metadata::KeyValue;
+
+        let batch = self.to_batch()?;
+        let kv: Vec<KeyValue> = self
+            .metadata
+            .iter()
+            .map(|(k, v)| KeyValue::new(k.clone(), v.clone()))
+            .collect();
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(Some(kv))
+            .build();
+        let file = File::create(path)?;
+        let mut writer = ParquetArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    pub fn finish(self, _path: &str) -> Result<()> {
+        anyhow::bail!("parquet/arrow output requires building with the 'arrow' feature")
+    }
+}
+
+/// One Pareto-front solution row for columnar output.
+#[derive(Clone, Debug)]
+pub struct ParetoRow {
+    pub rank: i32,
+    pub crowding: f64,
+    pub bpr: f64,
+    pub opr: f64,
+    pub eta_comp: f64,
+    pub eta_turb: f64,
+    pub tsfc: f64,
+    pub thrust: f64,
+    pub t4: f64,
+    pub status: i32,
+}
+
+/// Columnar writer for the NSGA-II Pareto front, mirroring [`ArrowWriter`] so
+/// `Optimize` results are self-describing Parquet just like sweeps.
+pub struct ArrowParetoWriter {
+    rows: Vec<ParetoRow>,
+    metadata: Vec<(String, String)>,
+}
+
+impl ArrowParetoWriter {
+    #[cfg(feature = "arrow")]
+    pub fn new(metadata: Vec<(String, String)>) -> Result<Self> {
+        Ok(Self { rows: Vec::new(), metadata })
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    pub fn new(_metadata: Vec<(String, String)>) -> Result<Self> {
+        anyhow::bail!("parquet/arrow output requires building with the 'arrow' feature")
+    }
+
+    pub fn push(&mut self, row: ParetoRow) {
+        self.rows.push(row);
+    }
+
+    #[cfg(feature = "arrow")]
+    pub fn finish(self, path: &str) -> Result<()> {
+        use arrow::array::{ArrayRef, Float64Array, Int32Array};
+        use arrow::datatypes::{DataType, Field, Schema};
+        use parquet::arrow::ArrowWriter as ParquetArrowWriter;
+        use parquet::file::metadata::KeyValue;
+        use parquet::file::properties::WriterProperties;
+        use std::sync::Arc;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("rank", DataType::Int32, false),
+            Field::new("crowding", DataType::Float64, false),
+            Field::new("bpr", DataType::Float64, false),
+            Field::new("opr", DataType::Float64, false),
+            Field::new("eta_comp", DataType::Float64, false),
+            Field::new("eta_turb", DataType::Float64, false),
+            Field::new("tsfc", DataType::Float64, false),
+            Field::new("thrust", DataType::Float64, false),
+            Field::new("t4", DataType::Float64, false),
+            Field::new("status", DataType::Int32, false),
+        ]));
+        let f64col = |sel: fn(&ParetoRow) -> f64| -> ArrayRef {
+            Arc::new(Float64Array::from(self.rows.iter().map(sel).collect::<Vec<_>>()))
+        };
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(self.rows.iter().map(|r| r.rank).collect::<Vec<_>>())),
+            f64col(|r| r.crowding),
+            f64col(|r| r.bpr),
+            f64col(|r| r.opr),
+            f64col(|r| r.eta_comp),
+            f64col(|r| r.eta_turb),
+            f64col(|r| r.tsfc),
+            f64col(|r| r.thrust),
+            f64col(|r| r.t4),
+            Arc::new(Int32Array::from(self.rows.iter().map(|r| r.status).collect::<Vec<_>>())),
+        ];
+        let batch = arrow::record_batch::RecordBatch::try_new(schema, columns)?;
+        let kv: Vec<KeyValue> = self
+            .metadata
+            .iter()
+            .map(|(k, v)| KeyValue::new(k.clone(), v.clone()))
+            .collect();
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(Some(kv))
+            .build();
+        let file = File::create(path)?;
+        let mut writer = ParquetArrowWriter::try_new(file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    pub fn finish(self, _path: &str) -> Result<()> {
+        anyhow::bail!("parquet/arrow output requires building with the 'arrow' feature")
+    }
+}
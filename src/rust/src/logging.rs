@@ -0,0 +1,62 @@
+//! Structured logging via `tracing`.
+//!
+//! Replaces the scattered `eprintln!("[minotaur] ...")` diagnostics with
+//! leveled [`tracing`] events and spans so runs can be silenced, made verbose,
+//! or piped into log aggregators. [`init`] wires up a `tracing-subscriber`
+//! with an env-filter seeded from the `--log-level` flag; the JSON format
+//! emits one structured record per line.
+//!
+//! Author: CSTNSystems
+//! Version: 2.20.0
+
+use clap::ValueEnum;
+
+/// Minimum event level emitted by the subscriber.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn as_filter(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+/// Event rendering format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable, one block per event.
+    Pretty,
+    /// One structured JSON record per line.
+    Json,
+}
+
+/// Initialize the global tracing subscriber.
+///
+/// The env-filter honours `RUST_LOG` when set, otherwise falls back to the
+/// level selected on the command line. Events are written to stderr so result
+/// bundles on stdout/files stay clean.
+pub fn init(level: LogLevel, format: LogFormat) {
+    use tracing_subscriber::{fmt, EnvFilter};
+
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(level.as_filter()));
+
+    let builder = fmt().with_env_filter(filter).with_writer(std::io::stderr);
+
+    match format {
+        LogFormat::Pretty => builder.init(),
+        LogFormat::Json => builder.json().flatten_event(true).init(),
+    }
+}
@@ -1,11 +1,20 @@
+mod bfgs;
+mod cg;
 mod config;
 mod ffi;
 mod io;
+mod logging;
 mod nsga2;
+mod parallel;
+mod termination;
+mod thermo;
+mod uncertainty;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use logging::{LogFormat, LogLevel};
 use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
 use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
@@ -35,6 +44,85 @@ struct Args {
     /// Run mode: "single" or "sweep" (legacy compatibility)
     #[arg(short, long, default_value = "single")]
     mode: String,
+
+    /// Result output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Csv, global = true)]
+    format: OutputFormat,
+
+    /// Minimum log level
+    #[arg(long, value_enum, default_value_t = LogLevel::Info, global = true)]
+    log_level: LogLevel,
+
+    /// Log output format
+    #[arg(long, value_enum, default_value_t = LogFormat::Pretty, global = true)]
+    log_format: LogFormat,
+
+    /// Worker threads for population/grid evaluation (1 = serial, v2.26)
+    #[arg(long, default_value = "1", global = true)]
+    threads: usize,
+}
+
+/// Result output backend selector (v2.18).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Row-oriented CSV (default, human-readable).
+    Csv,
+    /// Columnar Apache Parquet with embedded manifest metadata.
+    Parquet,
+    /// Alias for `parquet`: writes the same Parquet file through the same
+    /// Arrow backend (no Arrow IPC file is produced).
+    Arrow,
+}
+
+impl OutputFormat {
+    /// Whether this format uses the columnar [`io::ArrowWriter`] backend.
+    fn is_columnar(self) -> bool {
+        matches!(self, OutputFormat::Parquet | OutputFormat::Arrow)
+    }
+
+    /// File extension for a columnar result file.
+    fn extension(self) -> &'static str {
+        "parquet"
+    }
+}
+
+/// Pseudo-random generator backing the NSGA-II/SPEA2 variation operators. (v2.30)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum RngChoice {
+    /// Wrapping linear congruential generator (historical default).
+    Lcg,
+    /// Permuted congruential generator (PCG64 XSL-RR).
+    Pcg64,
+    /// ChaCha stream cipher reduced to 8 rounds.
+    Chacha8,
+}
+
+impl RngChoice {
+    fn kind(self) -> nsga2::RngKind {
+        match self {
+            RngChoice::Lcg => nsga2::RngKind::Lcg,
+            RngChoice::Pcg64 => nsga2::RngKind::Pcg64,
+            RngChoice::Chacha8 => nsga2::RngKind::ChaCha8,
+        }
+    }
+}
+
+/// Mutation operator backing the NSGA-II variation step. (v2.31)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum MutationChoice {
+    /// Polynomial mutation (historical default).
+    Polynomial,
+    /// Metropolis-style dual step (global resample or local log-uniform jitter).
+    Dual,
+}
+
+impl MutationChoice {
+    fn kind(self) -> nsga2::MutationKind {
+        match self {
+            MutationChoice::Polynomial => nsga2::MutationKind::Polynomial,
+            MutationChoice::Dual => nsga2::MutationKind::Dual,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -50,6 +138,22 @@ enum Commands {
         /// Generate JSON outputs
         #[arg(long)]
         json: bool,
+        /// Resume a previous sweep, skipping cells recorded in the
+        /// `<out>.progress.json` sidecar and appending new results (v2.23)
+        #[arg(long)]
+        resume: bool,
+    },
+    /// Gradient-based local refinement of a scalarized objective (v2.25)
+    Minimize {
+        /// Generate JSON outputs
+        #[arg(long)]
+        json: bool,
+    },
+    /// Back-solve for a cycle parameter that hits a target thrust (v2.24)
+    Design {
+        /// Generate JSON outputs
+        #[arg(long)]
+        json: bool,
     },
     /// Compute local sensitivities via finite differences
     Sensitivity {
@@ -87,6 +191,32 @@ enum Commands {
         /// Random seed for reproducibility
         #[arg(long, default_value = "42")]
         seed: u64,
+        /// Probability of memetic local-search refinement per rank-0 solution
+        /// each generation (0 disables it) (v2.22)
+        #[arg(long, default_value = "0.0")]
+        local_search_prob: f64,
+        /// Pseudo-random generator backing the variation operators (v2.30)
+        #[arg(long, value_enum, default_value_t = RngChoice::Lcg)]
+        rng: RngChoice,
+        /// Mutation operator for the variation step (v2.31)
+        #[arg(long, value_enum, default_value_t = MutationChoice::Polynomial)]
+        mutation: MutationChoice,
+        /// Write a per-generation convergence history (hypervolume, front size,
+        /// best TSFC/thrust, mean CV) to this CSV for post-processing (v2.31)
+        #[arg(long)]
+        history: Option<String>,
+        /// Generate JSON outputs
+        #[arg(long)]
+        json: bool,
+    },
+    /// Propagate input uncertainty via Monte Carlo sampling (v2.19)
+    Uncertainty {
+        /// Override the number of Monte Carlo samples from config
+        #[arg(long)]
+        samples: Option<usize>,
+    },
+    /// Run a mission-segment schedule and aggregate the mission totals (v2.33)
+    Mission {
         /// Generate JSON outputs
         #[arg(long)]
         json: bool,
@@ -124,6 +254,7 @@ struct Summary {
     tsfc_proxy: f64,
     thrust_proxy: f64,
     wall_time_ms: f64,
+    final_lambda: f64,
 }
 
 #[derive(Serialize)]
@@ -179,6 +310,8 @@ struct OptimizationOutput {
     hypervolume: Option<f64>,
     generations: usize,
     wall_time_ms: f64,
+    /// Extra objective evaluations spent on memetic local search (v2.22).
+    local_search_evals: usize,
 }
 
 #[derive(Serialize)]
@@ -201,6 +334,8 @@ struct ParetoSolution {
     tsfc: f64,
     thrust: f64,
     t4: f64,
+    /// Natural-sense value of each configured objective (v2.29).
+    objectives: Vec<f64>,
     status: i32,
     rank: usize,
     crowding_distance: f64,
@@ -213,6 +348,48 @@ struct ResultBundle {
     convergence: Option<Convergence>,
 }
 
+// v2.19: Monte Carlo uncertainty output structures
+#[derive(Serialize)]
+struct UncertaintyOutput {
+    manifest: Manifest,
+    samples_requested: usize,
+    samples_converged: usize,
+    parameters: Vec<String>,
+    outputs: HashMap<String, OutputSummary>,
+}
+
+#[derive(Serialize)]
+struct OutputSummary {
+    mean: f64,
+    std: f64,
+    p5: f64,
+    p50: f64,
+    p95: f64,
+    cdf: Vec<CdfPoint>,
+}
+
+#[derive(Serialize)]
+struct CdfPoint {
+    value: f64,
+    cumulative_probability: f64,
+}
+
+impl From<uncertainty::OutputStats> for OutputSummary {
+    fn from(s: uncertainty::OutputStats) -> Self {
+        OutputSummary {
+            mean: s.mean,
+            std: s.std,
+            p5: s.p5,
+            p50: s.p50,
+            p95: s.p95,
+            cdf: s.cdf.into_iter().map(|(value, cumulative_probability)| CdfPoint {
+                value,
+                cumulative_probability,
+            }).collect(),
+        }
+    }
+}
+
 // v2.4: Comparison output structures
 #[derive(Serialize)]
 struct ComparisonOutput {
@@ -257,6 +434,24 @@ fn compute_hash(data: &str) -> String {
             hasher.finish(), hasher.finish(), hasher.finish(), hasher.finish())
 }
 
+/// Sidecar progress manifest for resumable sweeps (v2.23).
+///
+/// Records the `config_hash` the sweep was started against, the full grid
+/// shape, and the `(i, j)` cells already solved. `--resume` reads this file,
+/// refuses to continue against an incompatible config, skips the completed
+/// cells, and keeps appending to the same output and manifest.
+#[derive(Serialize, Deserialize)]
+struct SweepProgress {
+    config_hash: String,
+    bpr_n: usize,
+    opr_n: usize,
+    completed: Vec<(usize, usize)>,
+}
+
+fn progress_path(out_path: &str) -> String {
+    format!("{}.progress.json", out_path)
+}
+
 fn get_timestamp() -> String {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -294,8 +489,42 @@ fn get_timestamp() -> String {
     format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hours, mins, secs)
 }
 
+/// Run a base solve inside a tracing span recording its inputs, iteration
+/// count, and wall-time. v2.20
+fn solve_traced(inp: ffi::MinotaurInput) -> ffi::MinotaurOutput {
+    let span = tracing::debug_span!("solve", bpr = inp.bpr, opr = inp.opr);
+    let _guard = span.enter();
+    let start = Instant::now();
+    let out = ffi::solve(inp);
+    debug!(
+        status = out.status,
+        iter = out.iter,
+        residual = out.final_residual,
+        wall_us = start.elapsed().as_micros() as u64,
+        "solve complete"
+    );
+    out
+}
+
+/// Span-wrapped variant of [`ffi::solve_ext`]. v2.20
+fn solve_ext_traced(ext: ffi::MinotaurInputExt) -> ffi::MinotaurOutput {
+    let span = tracing::debug_span!("solve_ext", bpr = ext.bpr, opr = ext.opr);
+    let _guard = span.enter();
+    let start = Instant::now();
+    let out = ffi::solve_ext(ext);
+    debug!(
+        status = out.status,
+        iter = out.iter,
+        residual = out.final_residual,
+        wall_us = start.elapsed().as_micros() as u64,
+        "solve_ext complete"
+    );
+    out
+}
+
 fn create_input(cfg: &config::Root, bpr: f64, opr: f64) -> ffi::MinotaurInput {
-    ffi::MinotaurInput {
+    let term = termination::TerminationCondition::from_tol(cfg.solver.tol);
+    let mut inp = ffi::MinotaurInput {
         mach: cfg.cycle.mach,
         alt_km: cfg.cycle.alt_km,
         bpr,
@@ -303,14 +532,71 @@ fn create_input(cfg: &config::Root, bpr: f64, opr: f64) -> ffi::MinotaurInput {
         eta_comp: cfg.cycle.eta_comp,
         eta_turb: cfg.cycle.eta_turb,
         eta_nozz: cfg.cycle.eta_nozz,
-        fuel_k: cfg.cycle.fuel_k,
+        // v2.37: when a fuel model is present, the heating value rescales the
+        // opaque fuel constant relative to the Jet-A reference.
+        fuel_k: cfg
+            .fuel
+            .as_ref()
+            .map(|f| f.effective_fuel_k(cfg.cycle.fuel_k))
+            .unwrap_or(cfg.cycle.fuel_k),
         max_iter: cfg.solver.max_iter,
         tol: cfg.solver.tol,
         damping: cfg.solver.damping,
         mass_tol: cfg.invariants.mass_tol,
         energy_tol: cfg.invariants.energy_tol,
         t4_max: cfg.constraints.t4_max,
+        globalize: cfg.solver.globalize_id(),
+        lm_lambda_init: cfg.solver.lm_lambda_init,
+        lm_lambda_up: cfg.solver.lm_lambda_up,
+        lm_lambda_down: cfg.solver.lm_lambda_down,
+        lm_lambda_cap: cfg.solver.lm_lambda_cap,
+        jac_reuse: if cfg.solver.jac_reuse { 1 } else { 0 },
+        jac_refresh_interval: cfg.solver.jac_refresh_interval,
+        jac_stagnation_factor: cfg.solver.jac_stagnation_factor,
+        term_mode: termination::TerminationMode::parse(&cfg.solver.term_mode)
+            .map(|m| m.id())
+            .unwrap_or(0),
+        abstol: cfg.solver.abstol.unwrap_or_else(|| term.abstol),
+        reltol: cfg.solver.reltol.unwrap_or_else(|| term.reltol),
+        stol: cfg.solver.stol.unwrap_or_else(|| term.stol),
+        // Cold start by default; sweeps opt in via `warm_start_from`.
+        warm_start: 0,
+        warm_bpr: 0.0,
+        warm_opr: 0.0,
+        warm_t4: 0.0,
+        // State floors off unless a [solver.floors] section opts in (v2.27).
+        floor_pressure: cfg.solver.floors.as_ref().map(|f| f.pressure).unwrap_or(0.0),
+        floor_temp: cfg.solver.floors.as_ref().map(|f| f.temperature).unwrap_or(0.0),
+        floor_massfrac: cfg.solver.floors.as_ref().map(|f| f.mass_fraction).unwrap_or(0.0),
+        // Single lumped stage unless a [turbomachinery] stack opts in (v2.28).
+        n_comp_stages: 0,
+        n_turb_stages: 0,
+        lp_comp_stages: 0,
+    };
+
+    // v2.28: when a multi-stage stack is declared, the stacked stage ratios and
+    // polytropic efficiencies override the cycle-level opr/eta_comp/eta_turb with
+    // their derived aggregates, and the stage counts go to the solver core.
+    if let Some(ref tm) = cfg.turbomachinery {
+        inp.opr = tm.overall_opr();
+        inp.eta_comp = tm.aggregate_eta_comp();
+        inp.eta_turb = tm.aggregate_eta_turb();
+        inp.n_comp_stages = tm.compressor.len() as i32;
+        inp.n_turb_stages = tm.turbine.len() as i32;
+        inp.lp_comp_stages = tm.lp_compressor_stages as i32;
     }
+
+    inp
+}
+
+/// Seed a cold input with a previously converged working state so the Newton
+/// iteration starts near the solution. Used by warm-started sweeps.
+fn warm_start_from(mut inp: ffi::MinotaurInput, state: &FinalState) -> ffi::MinotaurInput {
+    inp.warm_start = 1;
+    inp.warm_bpr = state.bpr;
+    inp.warm_opr = state.opr;
+    inp.warm_t4 = state.t4;
+    inp
 }
 
 // v2.4: Create extended input with component models and losses
@@ -323,6 +609,7 @@ fn create_input_ext(cfg: &config::Root, bpr: f64, opr: f64) -> ffi::MinotaurInpu
         ext.compressor_model = comp.compressor_id();
         ext.turbine_model = comp.turbine_id();
         ext.nozzle_model = comp.nozzle_id();
+        ext.gas_model = comp.gas_model_id();
     }
 
     // Apply loss coefficients
@@ -333,17 +620,77 @@ fn create_input_ext(cfg: &config::Root, bpr: f64, opr: f64) -> ffi::MinotaurInpu
         ext.nozzle_loss = losses.nozzle;
     }
 
-    // Apply degradation if specified
+    // Apply degradation if specified. v2.34: a time-resolved trajectory resolves
+    // the effective factors/adder from its per-1000-hour rates.
     if let Some(ref deg) = cfg.degradation {
-        ext.eta_comp_factor = deg.eta_comp_factor;
-        ext.eta_turb_factor = deg.eta_turb_factor;
-        ext.loss_adder = deg.loss_adder;
-        ext.is_degraded = if deg.is_degraded() { 1 } else { 0 };
+        let (eta_comp_factor, eta_turb_factor, loss_adder) = deg.effective();
+        ext.eta_comp_factor = eta_comp_factor;
+        ext.eta_turb_factor = eta_turb_factor;
+        ext.loss_adder = loss_adder;
+        ext.is_degraded = if eta_comp_factor < 1.0 || eta_turb_factor < 1.0 || loss_adder > 0.0 { 1 } else { 0 };
     }
 
     ext
 }
 
+/// v2.38: Solve a single operating point under the configured solve strategy.
+/// "damped" preserves the historical single-solve path exactly; "newton" takes
+/// the same step undamped; "continuation" marches a homotopy parameter from an
+/// easy anchor point to the target, warm-starting each step.
+fn solve_with_strategy(cfg: &config::Root, bpr: f64, opr: f64) -> Result<ffi::MinotaurOutput> {
+    match cfg.solver.strategy.as_str() {
+        "continuation" => solve_continuation(cfg, bpr, opr),
+        "newton" => {
+            let mut inp = create_input(cfg, bpr, opr);
+            inp.damping = 1.0; // full, undamped Newton step
+            Ok(solve_traced(inp))
+        }
+        _ => Ok(solve_traced(create_input(cfg, bpr, opr))),
+    }
+}
+
+/// Continuation/homotopy solve (v2.38). Anchored at a modest pressure ratio with
+/// degradation switched off, the solver marches `continuation_steps` increments
+/// of a scalar `lambda` in [0, 1] toward the target OPR and degradation state,
+/// using each converged point as the warm start for the next. If a step fails to
+/// converge the march aborts, reporting the `lambda` that could not be reached.
+fn solve_continuation(cfg: &config::Root, bpr: f64, opr: f64) -> Result<ffi::MinotaurOutput> {
+    let steps = cfg.solver.continuation_steps.unwrap_or(1).max(1);
+    let target = create_input_ext(cfg, bpr, opr);
+    // Easy anchor: halve the pressure ratio (never below a modest floor, and
+    // never above the target) and start from the nominal, undegraded engine.
+    let easy_opr = (opr * 0.5).clamp(2.0_f64.min(opr), opr);
+    let mut last: Option<ffi::MinotaurOutput> = None;
+    for k in 0..=steps {
+        let lambda = k as f64 / steps as f64;
+        let mut ext = target;
+        ext.opr = easy_opr + lambda * (opr - easy_opr);
+        ext.eta_comp_factor = 1.0 + lambda * (target.eta_comp_factor - 1.0);
+        ext.eta_turb_factor = 1.0 + lambda * (target.eta_turb_factor - 1.0);
+        ext.loss_adder = lambda * target.loss_adder;
+        ext.is_degraded =
+            if ext.eta_comp_factor < 1.0 || ext.eta_turb_factor < 1.0 || ext.loss_adder > 0.0 { 1 } else { 0 };
+        if let Some(prev) = last {
+            ext.warm_start = 1;
+            ext.warm_bpr = prev.final_bpr;
+            ext.warm_opr = ext.opr;
+            ext.warm_t4 = prev.t4;
+        }
+        let out = solve_ext_traced(ext);
+        if out.status != 0 {
+            bail!(
+                "continuation stalled at lambda={:.3} (opr={:.2}): solver status {} ({})",
+                lambda,
+                ext.opr,
+                out.status,
+                ffi::status_name(out.status)
+            );
+        }
+        last = Some(out);
+    }
+    last.context("continuation produced no solve")
+}
+
 fn create_manifest(cfg: &config::Root, cfg_text: &str) -> Manifest {
     Manifest {
         schema_version: SCHEMA_VERSION.to_string(),
@@ -359,6 +706,27 @@ fn create_manifest(cfg: &config::Root, cfg_text: &str) -> Manifest {
     }
 }
 
+/// Manifest fields embedded as Parquet key/value file metadata so a columnar
+/// result file is self-describing.
+fn manifest_metadata(manifest: &Manifest) -> Vec<(String, String)> {
+    vec![
+        ("schema_version".to_string(), manifest.schema_version.clone()),
+        ("config_hash".to_string(), manifest.config_hash.clone()),
+        ("solver_version".to_string(), manifest.solver_version.clone()),
+        ("timestamp_utc".to_string(), manifest.timestamp_utc.clone()),
+        ("program_id".to_string(), manifest.CSTNSystems_program_id.clone()),
+    ]
+}
+
+/// Reduced-order topping-cycle thermal-efficiency proxy (v2.35). The solver
+/// exports a specific-consumption proxy rather than a thermal efficiency, so the
+/// combined-cycle estimator uses the bounded monotone surrogate
+/// `1 / (1 + tsfc_proxy)`, which falls in `(0, 1)` and decreases with
+/// consumption.
+fn topping_efficiency(out: &ffi::MinotaurOutput) -> f64 {
+    1.0 / (1.0 + out.tsfc_proxy.max(0.0))
+}
+
 fn create_summary(out: &ffi::MinotaurOutput, wall_time_ms: f64) -> Summary {
     Summary {
         status: out.status,
@@ -372,6 +740,7 @@ fn create_summary(out: &ffi::MinotaurOutput, wall_time_ms: f64) -> Summary {
         tsfc_proxy: out.tsfc_proxy,
         thrust_proxy: out.thrust_proxy,
         wall_time_ms,
+        final_lambda: out.final_lambda,
     }
 }
 
@@ -379,21 +748,32 @@ fn create_summary(out: &ffi::MinotaurOutput, wall_time_ms: f64) -> Summary {
 // Run Modes
 // ============================================================================
 
-fn run_single(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
+fn run_single(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool, format: OutputFormat) -> Result<()> {
     let bpr = cfg.cycle.bpr.context("cycle.bpr required for single mode")?;
-    let opr = cfg.cycle.opr.context("cycle.opr required for single mode")?;
-
-    let inp = create_input(cfg, bpr, opr);
+    // v2.28: a declared stage stack supplies the overall pressure ratio, so
+    // cycle.opr is optional when [turbomachinery] is present.
+    let opr = match cfg.turbomachinery {
+        Some(ref tm) => tm.overall_opr(),
+        None => cfg.cycle.opr.context("cycle.opr required for single mode")?,
+    };
 
     let start = Instant::now();
-    let out = ffi::solve(inp);
+    // v2.38: solve under the configured strategy (damped / newton / continuation).
+    let out = solve_with_strategy(cfg, bpr, opr)?;
     let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
-    // CSV output
-    let mut w = io::CsvWriter::create(out_path)?;
-    w.write_header()?;
-    w.write_row("baseline", bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
-    w.flush()?;
+    if format.is_columnar() {
+        let parquet_path = out_path.replace(".csv", &format!(".{}", format.extension()));
+        let mut aw = io::ArrowWriter::new(manifest_metadata(&create_manifest(cfg, cfg_text)))?;
+        aw.push(io::ResultRow::from_output("baseline", bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out));
+        aw.finish(&parquet_path)?;
+        info!("columnar output: {}", parquet_path);
+    } else {
+        let mut w = io::CsvWriter::create(out_path)?;
+        w.write_header()?;
+        w.write_row("baseline", bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
+        w.flush()?;
+    }
 
     eprintln!(
         "[minotaur] status={} ({}) iter={} residual={:.2e} t4={:.1} tsfc={:.4} thrust={:.4}",
@@ -405,6 +785,47 @@ fn run_single(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: b
         out.tsfc_proxy,
         out.thrust_proxy
     );
+    if cfg.solver.floors.is_some() {
+        eprintln!("  state floors triggered on {} iteration(s)", out.clamp_iters);
+    }
+    // v2.38: flag a suspiciously early convergence against the configured floor.
+    if out.status == 0 && out.iter < cfg.solver.min_iter {
+        warn!(
+            "converged in {} iteration(s), below min_iter={}",
+            out.iter, cfg.solver.min_iter
+        );
+    }
+    if let Some(tm) = &cfg.turbomachinery {
+        eprintln!(
+            "  turbomachinery stack: {}C/{}T stages, derived opr={:.3}, eta_comp={:.4}, eta_turb={:.4}",
+            tm.compressor.len(), tm.turbine.len(),
+            tm.overall_opr(), tm.aggregate_eta_comp(), tm.aggregate_eta_turb()
+        );
+    }
+    // v2.35: combined-cycle estimate from the heat-recovery bottoming section.
+    // The topping thermal efficiency is a reduced-order proxy derived from the
+    // specific-consumption output; the exhaust temperature is taken as T4.
+    if let Some(b) = &cfg.bottoming {
+        let topping_eta = topping_efficiency(&out);
+        let bottoming_power = b.bottoming_power(out.t4);
+        let combined_eta = b.combined_efficiency(topping_eta, out.t4);
+        eprintln!(
+            "  bottoming cycle: recovered_heat={:.1} K, bottoming_power={:.2}, topping_eta={:.4}, combined_eta={:.4}",
+            b.recovered_heat(out.t4), bottoming_power, topping_eta, combined_eta
+        );
+    }
+
+    // v2.37: report the derived fuel-air and equivalence ratios when a fuel
+    // model is active. The commanded turbine-inlet temperature is the T4 cap.
+    if let Some(fuel) = &cfg.fuel {
+        if let Some((lhv, far_stoich)) = fuel.resolve() {
+            let far = fuel.combustor_far(cfg.constraints.t4_max);
+            eprintln!(
+                "  fuel: {} (LHV={:.1} MJ/kg, far_stoich={:.3}) -> far={:.4}, phi={:.3}",
+                fuel.fuel_type, lhv, far_stoich, far, far / far_stoich
+            );
+        }
+    }
 
     // JSON output (v2.5 result bundle with schema v2.0.0)
     if json_output {
@@ -416,46 +837,577 @@ fn run_single(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: b
         };
         let json = serde_json::to_string_pretty(&bundle)?;
         fs::write(&json_path, json)?;
-        eprintln!("[minotaur] JSON bundle: {}", json_path);
+        info!("JSON bundle: {}", json_path);
     }
 
     if out.status != 0 {
-        eprintln!("[minotaur] WARNING: solver did not converge");
+        warn!("solver did not converge");
     }
 
     Ok(())
 }
 
-fn run_sweep(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
-    let sweep = cfg.sweep.as_ref().context("[sweep] section required for sweep mode")?;
+/// Gradient-based local refinement (v2.25): minimize the scalarized objective
+/// `w_tsfc·tsfc + w_thrust·(−thrust)` over the same `(bpr, opr, eta_comp,
+/// eta_turb)` bound box as NSGA-II, with `t4 <= t4_max` folded into the merit as
+/// an exact penalty. The projected-BFGS loop evaluates the merit through
+/// `ffi::solve` and its gradient through the exact forward-mode Jacobian, giving
+/// fast local polishing of a Pareto point.
+fn run_minimize(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
+    let mcfg = cfg.minimize.clone().unwrap_or_default();
+
+    // Decision variables and bounds mirror `run_optimize`.
+    let bounds = vec![
+        (0.2, 1.5),  // bpr
+        (4.0, 16.0), // opr
+        (0.75, 0.90), // eta_comp
+        (0.80, 0.92), // eta_turb
+    ];
+    let mid = |(lo, hi): (f64, f64)| 0.5 * (lo + hi);
+    let x0 = vec![
+        cfg.cycle.bpr.unwrap_or_else(|| mid(bounds[0])),
+        cfg.cycle.opr.unwrap_or_else(|| mid(bounds[1])),
+        cfg.cycle.eta_comp,
+        cfg.cycle.eta_turb,
+    ];
+
+    let t4_max = cfg.constraints.t4_max;
+    let (w0, w1, penalty) = (mcfg.w_tsfc, mcfg.w_thrust, mcfg.penalty);
+
+    // Build the solver input for a decision vector, reusing the shared cycle
+    // settings and overriding only the free variables.
+    let input_for = |x: &[f64]| -> ffi::MinotaurInput {
+        let mut inp = create_input(cfg, x[0], x[1]);
+        inp.eta_comp = x[2];
+        inp.eta_turb = x[3];
+        inp
+    };
+
+    // Merit: scalarized objective plus exact penalty; non-converged points are
+    // pushed far uphill so the line search steps away from them.
+    let merit_fn = |x: &[f64]| -> f64 {
+        let out = solve_traced(input_for(x));
+        if out.status != 0 {
+            return 1e6;
+        }
+        w0 * out.tsfc_proxy + w1 * (-out.thrust_proxy) + penalty * (out.t4 - t4_max).max(0.0)
+    };
+
+    // Gradient of the merit from the exact Jacobian. Columns of the 6×3 Jacobian
+    // are (tsfc, thrust, t4); rows 2..6 are (bpr, opr, eta_comp, eta_turb).
+    let grad_fn = |x: &[f64]| -> Vec<f64> {
+        let jac = ffi::compute_jacobian(cfg.cycle.mach, cfg.cycle.alt_km, x[0], x[1], x[2], x[3], t4_max);
+        let active = if jac.base_t4 > t4_max { penalty } else { 0.0 };
+        (2..6)
+            .map(|p| w0 * jac.jacobian[p][0] + w1 * (-jac.jacobian[p][1]) + active * jac.jacobian[p][2])
+            .collect()
+    };
+
+    let bfgs_cfg = bfgs::BfgsConfig {
+        bounds,
+        max_iter: mcfg.max_iter,
+        grad_tol: mcfg.grad_tol,
+        ..Default::default()
+    };
+
+    info!("starting projected-BFGS refinement");
+    eprintln!(
+        "  weights: tsfc={}, -thrust={}, penalty={}; max_iter={}, grad_tol={:.0e}",
+        w0, w1, penalty, mcfg.max_iter, mcfg.grad_tol
+    );
+
+    let start = Instant::now();
+    let res = bfgs::optimize(&bfgs_cfg, &x0, merit_fn, grad_fn);
+    let out = solve_traced(input_for(&res.x));
+    let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
     let mut w = io::CsvWriter::create(out_path)?;
     w.write_header()?;
+    w.write_row("minimize", res.x[0], res.x[1], cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
+    w.flush()?;
+
+    eprintln!(
+        "[minotaur] minimize: bpr={:.4} opr={:.4} eta_comp={:.4} eta_turb={:.4} merit={:.6} grad={:.2e} iters={} ({})",
+        res.x[0], res.x[1], res.x[2], res.x[3], res.f, res.grad_norm, res.iterations,
+        if res.converged { "converged" } else { "stopped" }
+    );
+
+    if json_output {
+        let json_path = out_path.replace(".csv", ".json");
+
+        #[derive(Serialize)]
+        struct MinimizeOutput {
+            manifest: Manifest,
+            weights: (f64, f64),
+            penalty: f64,
+            solution: Vec<f64>,
+            merit: f64,
+            grad_norm: f64,
+            iterations: usize,
+            converged: bool,
+            summary: Summary,
+            wall_time_ms: f64,
+        }
+
+        let min_output = MinimizeOutput {
+            manifest: create_manifest(cfg, cfg_text),
+            weights: (w0, w1),
+            penalty,
+            solution: res.x.clone(),
+            merit: res.f,
+            grad_norm: res.grad_norm,
+            iterations: res.iterations,
+            converged: res.converged,
+            summary: create_summary(&out, wall_time_ms),
+            wall_time_ms,
+        };
+
+        let json = serde_json::to_string_pretty(&min_output)?;
+        fs::write(&json_path, json)?;
+        info!("JSON minimize bundle: {}", json_path);
+    }
+
+    if out.status != 0 {
+        warn!("refined point does not converge");
+    }
+
+    Ok(())
+}
+
+/// Inverse "design" solve (v2.24): back-solve for the free cycle parameter that
+/// drives the target quantity to `design.value`, subject to the forward solve's
+/// own `t4_max` limiter. The target residual `thrust_proxy(x) − target` is
+/// bracketed over `[design.min, design.max]` and bisected to tolerance; only
+/// points that themselves converge (`status == 0`) are accepted, so an
+/// infeasible target fails with a clear message instead of a bogus root.
+fn run_design(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
+    let design = cfg.design.as_ref().context("[design] section required for design mode")?;
+
+    if design.target != "thrust" {
+        anyhow::bail!(
+            "design.target '{}' unsupported: the solver exports no mass-flow quantity, only 'thrust' can be targeted",
+            design.target
+        );
+    }
+    if !(design.min < design.max) {
+        anyhow::bail!("design.min ({}) must be below design.max ({})", design.min, design.max);
+    }
+
+    // Resolve the fixed parameter (the one we are *not* back-solving for).
+    let fixed_bpr = cfg.cycle.bpr;
+    let fixed_opr = cfg.cycle.opr;
+    let eval_at = |x: f64| -> Result<ffi::MinotaurOutput> {
+        let (bpr, opr) = match design.free.as_str() {
+            "opr" => (fixed_bpr.context("cycle.bpr required when design.free = opr")?, x),
+            "bpr" => (x, fixed_opr.context("cycle.opr required when design.free = bpr")?),
+            other => anyhow::bail!("design.free '{}' must be 'bpr' or 'opr'", other),
+        };
+        Ok(solve_traced(create_input(cfg, bpr, opr)))
+    };
+
+    let start = Instant::now();
+
+    // Residual at a converged point, or None if the point does not converge.
+    let residual = |x: f64| -> Result<Option<f64>> {
+        let out = eval_at(x)?;
+        Ok(if out.status == 0 { Some(out.thrust_proxy - design.value) } else { None })
+    };
+
+    let (mut lo, mut hi) = (design.min, design.max);
+    let r_lo = residual(lo)?.with_context(|| format!("free = {} does not converge at lower bound {}", design.free, lo))?;
+    let r_hi = residual(hi)?.with_context(|| format!("free = {} does not converge at upper bound {}", design.free, hi))?;
+    if r_lo.signum() == r_hi.signum() {
+        anyhow::bail!(
+            "target thrust {:.4} infeasible within [{}, {}]: reachable thrust {:.4}..{:.4}",
+            design.value, lo, hi, r_lo + design.value, r_hi + design.value
+        );
+    }
+
+    // Bisection: keep the half-interval whose endpoints straddle the root.
+    let tol = cfg.solver.tol.max(1e-6) * design.value.abs().max(1.0);
+    let lo_sign = r_lo.signum();
+    let mut mid = 0.5 * (lo + hi);
+    let mut iters = 0usize;
+    for _ in 0..100 {
+        iters += 1;
+        mid = 0.5 * (lo + hi);
+        match residual(mid)? {
+            Some(r) => {
+                if r.abs() <= tol || (hi - lo) <= tol {
+                    break;
+                }
+                if r.signum() == lo_sign {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            None => {
+                // Non-converged midpoint: shrink from the side that still
+                // brackets by pulling the upper bound inwards.
+                hi = mid;
+            }
+        }
+    }
+
+    let (bpr, opr) = match design.free.as_str() {
+        "opr" => (fixed_bpr.unwrap(), mid),
+        _ => (mid, fixed_opr.unwrap()),
+    };
+    let out = solve_traced(create_input(cfg, bpr, opr));
+    let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let mut w = io::CsvWriter::create(out_path)?;
+    w.write_header()?;
+    w.write_row("design", bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
+    w.flush()?;
+
+    eprintln!(
+        "[minotaur] design: {} = {:.6} hits thrust {:.4} (target {:.4}) in {} bisections, status={} ({})",
+        design.free, mid, out.thrust_proxy, design.value, iters,
+        out.status, ffi::status_name(out.status)
+    );
+
+    if json_output {
+        let json_path = out_path.replace(".csv", ".json");
+
+        #[derive(Serialize)]
+        struct DesignOutput {
+            manifest: Manifest,
+            target: String,
+            target_value: f64,
+            free_var: String,
+            solved_value: f64,
+            bisections: usize,
+            bpr: f64,
+            opr: f64,
+            summary: Summary,
+            wall_time_ms: f64,
+        }
+
+        let design_output = DesignOutput {
+            manifest: create_manifest(cfg, cfg_text),
+            target: design.target.clone(),
+            target_value: design.value,
+            free_var: design.free.clone(),
+            solved_value: mid,
+            bisections: iters,
+            bpr,
+            opr,
+            summary: create_summary(&out, wall_time_ms),
+            wall_time_ms,
+        };
+
+        let json = serde_json::to_string_pretty(&design_output)?;
+        fs::write(&json_path, json)?;
+        info!("JSON design bundle: {}", json_path);
+    }
+
+    if out.status != 0 {
+        warn!("design point does not converge at the solved parameter");
+    }
+
+    Ok(())
+}
+
+// v2.21: warm-start iteration accounting in the sweep summary.
+#[derive(Serialize)]
+struct WarmStartStats {
+    total_iterations: i64,
+    cold_baseline_per_cell: f64,
+    iterations_saved: f64,
+    savings_pct: f64,
+    cold_fallbacks: usize,
+}
+
+// v2.26: parallel-evaluation accounting in the sweep summary.
+#[derive(Serialize)]
+struct ParallelStats {
+    threads: usize,
+    solver_time_ms: f64,
+}
+
+#[derive(Serialize)]
+struct ParameterRanges {
+    bpr: (f64, f64, usize),
+    opr: (f64, f64, usize),
+}
+
+#[derive(Serialize)]
+struct SweepSummary {
+    manifest: Manifest,
+    total_runs: usize,
+    converged_runs: usize,
+    convergence_rate: f64,
+    wall_time_ms: f64,
+    parameter_ranges: ParameterRanges,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warm_start: Option<WarmStartStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel: Option<ParallelStats>,
+}
+
+/// Write the `*_summary.json` bundle shared by the serial (warm-started) and
+/// parallel sweep paths.
+#[allow(clippy::too_many_arguments)]
+fn write_sweep_summary(
+    cfg: &config::Root,
+    cfg_text: &str,
+    out_path: &str,
+    sweep: &config::Sweep,
+    total: usize,
+    converged: usize,
+    conv_rate: f64,
+    wall_time_ms: f64,
+    warm_start: Option<WarmStartStats>,
+    parallel: Option<(usize, f64)>,
+) -> Result<()> {
+    let json_path = out_path.replace(".csv", "_summary.json");
+    let summary = SweepSummary {
+        manifest: create_manifest(cfg, cfg_text),
+        total_runs: total,
+        converged_runs: converged,
+        convergence_rate: conv_rate,
+        wall_time_ms,
+        parameter_ranges: ParameterRanges {
+            bpr: (sweep.bpr_min, sweep.bpr_max, sweep.bpr_n),
+            opr: (sweep.opr_min, sweep.opr_max, sweep.opr_n),
+        },
+        warm_start,
+        parallel: parallel.map(|(threads, solver_time_ms)| ParallelStats { threads, solver_time_ms }),
+    };
+    let json = serde_json::to_string_pretty(&summary)?;
+    fs::write(&json_path, json)?;
+    info!("JSON summary: {}", json_path);
+    Ok(())
+}
+
+fn run_sweep(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool, resume: bool, threads: usize, format: OutputFormat) -> Result<()> {
+    let sweep = cfg.sweep.as_ref().context("[sweep] section required for sweep mode")?;
+
+    // v2.36: space-filling sampling modes route to a dedicated DoE path; the
+    // grid machinery below (serpentine warm-starting, resumable sidecar) is
+    // specific to the dense rectangular grid.
+    if sweep.mode != "grid" {
+        if resume {
+            anyhow::bail!("--resume is only supported for the 'grid' sampling mode");
+        }
+        return run_sweep_doe(cfg, cfg_text, out_path, sweep, json_output, threads, format);
+    }
+
+    let columnar = format.is_columnar();
+    if columnar && resume {
+        anyhow::bail!("--resume is only supported for row (CSV) output; columnar backends rewrite the whole file");
+    }
+
+    // Resumable-sweep bookkeeping (v2.23). Completed cells from a prior run are
+    // loaded from the sidecar and skipped; the running progress is flushed back
+    // periodically so an interruption costs at most a handful of cells.
+    let config_hash = compute_hash(cfg_text);
+    let prog_path = progress_path(out_path);
+    let mut completed: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+    if resume {
+        let text = fs::read_to_string(&prog_path)
+            .with_context(|| format!("--resume: no progress file at {}", prog_path))?;
+        let prior: SweepProgress = serde_json::from_str(&text)
+            .context("--resume: could not parse progress file")?;
+        if prior.config_hash != config_hash {
+            anyhow::bail!(
+                "--resume: config_hash mismatch (progress {}, current {}); refusing to mix incompatible results",
+                prior.config_hash, config_hash
+            );
+        }
+        if prior.bpr_n != sweep.bpr_n || prior.opr_n != sweep.opr_n {
+            anyhow::bail!(
+                "--resume: grid shape changed ({}x{} -> {}x{})",
+                prior.bpr_n, prior.opr_n, sweep.bpr_n, sweep.opr_n
+            );
+        }
+        completed = prior.completed.into_iter().collect();
+        info!("resuming sweep: {} of {} cells already complete", completed.len(), sweep.bpr_n * sweep.opr_n);
+    }
+
+    let mut w = if columnar {
+        None
+    } else if resume {
+        Some(io::CsvWriter::open_append(out_path)?)
+    } else {
+        Some(io::CsvWriter::create(out_path)?)
+    };
+    if let Some(w) = w.as_mut() {
+        if !resume {
+            w.write_header()?;
+        }
+    }
+    // Seed the sidecar before any solving so a crash on the first cell still
+    // leaves a valid manifest to resume from.
+    let save_progress = |completed: &std::collections::HashSet<(usize, usize)>| -> Result<()> {
+        let prog = SweepProgress {
+            config_hash: config_hash.clone(),
+            bpr_n: sweep.bpr_n,
+            opr_n: sweep.opr_n,
+            completed: completed.iter().copied().collect(),
+        };
+        fs::write(&prog_path, serde_json::to_string_pretty(&prog)?)?;
+        Ok(())
+    };
+    save_progress(&completed)?;
+    let mut arrow = if columnar {
+        Some(io::ArrowWriter::new(manifest_metadata(&create_manifest(cfg, cfg_text)))?)
+    } else {
+        None
+    };
 
     let mut total = 0;
     let mut converged = 0;
     let mut results: Vec<(String, f64, f64, ffi::MinotaurOutput)> = Vec::new();
 
+    // Warm-start bookkeeping (v2.21): the grid is walked in serpentine order so
+    // each solve can be seeded from the previous converged point, and we track
+    // the iteration count against a cold-start baseline to report the saving.
+    let mut warm_state: Option<FinalState> = None;
+    let mut total_iters: i64 = 0;
+    let mut cold_iter_sum: i64 = 0;
+    let mut cold_cells: i64 = 0;
+    let mut warm_fallbacks = 0;
+    // v2.27: count iterations where a configured state floor clamped a quantity.
+    let mut clamp_total: i64 = 0;
+
     let start = Instant::now();
 
+    let bpr_at = |i: usize| if sweep.bpr_n > 1 {
+        sweep.bpr_min + (sweep.bpr_max - sweep.bpr_min) * (i as f64) / ((sweep.bpr_n - 1) as f64)
+    } else {
+        sweep.bpr_min
+    };
+    let opr_at = |j: usize| if sweep.opr_n > 1 {
+        sweep.opr_min + (sweep.opr_max - sweep.opr_min) * (j as f64) / ((sweep.opr_n - 1) as f64)
+    } else {
+        sweep.opr_min
+    };
+
+    // Parallel path (v2.26): each cell is an independent cold solve, so the grid
+    // is evaluated across `threads` workers. Results are collected in a stable
+    // row-major buffer before any writing, so the output is identical for any
+    // thread count. Warm-starting (which is inherently sequential) is skipped
+    // here in favour of concurrency.
+    if threads > 1 {
+        let cells: Vec<(usize, usize)> = (0..sweep.bpr_n)
+            .flat_map(|i| (0..sweep.opr_n).map(move |j| (i, j)))
+            .filter(|ij| !completed.contains(ij))
+            .collect();
+
+        let solved = parallel::par_map(&cells, threads, |_, &(i, j)| {
+            let cold = create_input(cfg, bpr_at(i), opr_at(j));
+            let t = Instant::now();
+            let out = solve_traced(cold);
+            (out, t.elapsed().as_secs_f64() * 1000.0)
+        });
+
+        let mut solver_time_ms = 0.0;
+        for (&(i, j), (out, solve_ms)) in cells.iter().zip(&solved) {
+            solver_time_ms += solve_ms;
+            total_iters += out.iter as i64;
+            cold_iter_sum += out.iter as i64;
+            cold_cells += 1;
+            clamp_total += out.clamp_iters as i64;
+
+            let case = format!("sweep_{:04}_{:04}", i, j);
+            if let Some(w) = w.as_mut() {
+                w.write_row(&case, bpr_at(i), opr_at(j), cfg.cycle.mach, cfg.cycle.alt_km, out)?;
+            }
+            if let Some(arrow) = arrow.as_mut() {
+                arrow.push(io::ResultRow::from_output(&case, bpr_at(i), opr_at(j), cfg.cycle.mach, cfg.cycle.alt_km, out));
+            }
+            if json_output {
+                results.push((case, bpr_at(i), opr_at(j), out.clone()));
+            }
+
+            total += 1;
+            if out.status == 0 {
+                converged += 1;
+            }
+            completed.insert((i, j));
+        }
+
+        if let Some(w) = w.as_mut() {
+            w.flush()?;
+        }
+        save_progress(&completed)?;
+        if let Some(arrow) = arrow.take() {
+            let parquet_path = out_path.replace(".csv", &format!(".{}", format.extension()));
+            arrow.finish(&parquet_path)?;
+            info!("columnar output: {}", parquet_path);
+        }
+        if let Some(mut w) = w {
+            w.flush()?;
+        }
+
+        let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+        let conv_rate = if total > 0 { converged as f64 / total as f64 } else { 0.0 };
+        eprintln!(
+            "[minotaur] sweep complete: {}/{} converged ({:.1}%) in {:.1}ms",
+            converged, total, 100.0 * conv_rate, wall_time_ms
+        );
+        eprintln!(
+            "[minotaur] parallel: {} threads, solver time {:.1}ms, speedup {:.2}x",
+            threads, solver_time_ms, solver_time_ms / wall_time_ms.max(1e-9)
+        );
+        if cfg.solver.floors.is_some() {
+            eprintln!("[minotaur] state floors triggered on {} iteration(s) across the grid", clamp_total);
+        }
+
+        if json_output {
+            write_sweep_summary(cfg, cfg_text, out_path, sweep, total, converged, conv_rate, wall_time_ms, None, Some((threads, solver_time_ms)))?;
+        }
+
+        return Ok(());
+    }
+
     for i in 0..sweep.bpr_n {
-        let bpr = if sweep.bpr_n > 1 {
-            sweep.bpr_min + (sweep.bpr_max - sweep.bpr_min) * (i as f64) / ((sweep.bpr_n - 1) as f64)
-        } else {
-            sweep.bpr_min
-        };
+        let bpr = bpr_at(i);
 
-        for j in 0..sweep.opr_n {
-            let opr = if sweep.opr_n > 1 {
-                sweep.opr_min + (sweep.opr_max - sweep.opr_min) * (j as f64) / ((sweep.opr_n - 1) as f64)
-            } else {
-                sweep.opr_min
+        // Boustrophedon traversal: reverse the opr scan on odd rows so the last
+        // point of one row neighbours the first point of the next.
+        let reverse = i % 2 == 1;
+        for jj in 0..sweep.opr_n {
+            let j = if reverse { sweep.opr_n - 1 - jj } else { jj };
+            let opr = opr_at(j);
+
+            if completed.contains(&(i, j)) {
+                continue;
+            }
+
+            let cold = create_input(cfg, bpr, opr);
+            let (out, warmed) = match warm_state {
+                Some(ref state) => {
+                    let out = solve_traced(warm_start_from(cold, state));
+                    if out.status == 0 {
+                        (out, true)
+                    } else {
+                        // Warm guess failed to converge; retry once from cold.
+                        warm_fallbacks += 1;
+                        (solve_traced(cold), false)
+                    }
+                }
+                None => (solve_traced(cold), false),
             };
 
-            let inp = create_input(cfg, bpr, opr);
-            let out = ffi::solve(inp);
+            total_iters += out.iter as i64;
+            clamp_total += out.clamp_iters as i64;
+            if !warmed {
+                cold_iter_sum += out.iter as i64;
+                cold_cells += 1;
+            }
+
             let case = format!("sweep_{:04}_{:04}", i, j);
-            w.write_row(&case, bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
+            debug!(case = %case, bpr, opr, warm = warmed, status = out.status, iter = out.iter, "sweep cell");
+            if let Some(w) = w.as_mut() {
+                w.write_row(&case, bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out)?;
+            }
+            if let Some(arrow) = arrow.as_mut() {
+                arrow.push(io::ResultRow::from_output(&case, bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, &out));
+            }
 
             if json_output {
                 results.push((case, bpr, opr, out));
@@ -464,55 +1416,175 @@ fn run_sweep(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bo
             total += 1;
             if out.status == 0 {
                 converged += 1;
+                // Carry this converged working state forward as the next seed.
+                warm_state = Some(FinalState {
+                    bpr: out.final_bpr,
+                    opr,
+                    t4: out.t4,
+                });
+            }
+
+            // Record the cell as done and flush the output + sidecar every so
+            // often so an interrupted run can be continued with --resume.
+            completed.insert((i, j));
+            if total % 16 == 0 {
+                if let Some(w) = w.as_mut() {
+                    w.flush()?;
+                }
+                save_progress(&completed)?;
             }
         }
     }
+    save_progress(&completed)?;
 
-    w.flush()?;
+    // Estimate iterations saved relative to solving every cell cold, using the
+    // mean cold-solve cost as the per-cell baseline.
+    let cold_baseline = if cold_cells > 0 {
+        cold_iter_sum as f64 / cold_cells as f64
+    } else {
+        0.0
+    };
+    let projected_cold = cold_baseline * total as f64;
+    let iters_saved = (projected_cold - total_iters as f64).max(0.0);
+    let savings_pct = if projected_cold > 0.0 {
+        100.0 * iters_saved / projected_cold
+    } else {
+        0.0
+    };
+
+    if let Some(mut w) = w {
+        w.flush()?;
+    }
+    if let Some(arrow) = arrow {
+        let parquet_path = out_path.replace(".csv", &format!(".{}", format.extension()));
+        arrow.finish(&parquet_path)?;
+        info!("columnar output: {}", parquet_path);
+    }
     let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
+    let conv_rate = if total > 0 { converged as f64 / total as f64 } else { 0.0 };
     eprintln!(
         "[minotaur] sweep complete: {}/{} converged ({:.1}%) in {:.1}ms",
         converged,
         total,
-        100.0 * converged as f64 / total as f64,
+        100.0 * conv_rate,
         wall_time_ms
     );
+    eprintln!(
+        "[minotaur] warm-start: {} iters total, ~{:.0} saved ({:.1}%), {} cold fallback(s)",
+        total_iters, iters_saved, savings_pct, warm_fallbacks
+    );
+    if cfg.solver.floors.is_some() {
+        eprintln!("[minotaur] state floors triggered on {} iteration(s) across the grid", clamp_total);
+    }
 
     if json_output {
-        let json_path = out_path.replace(".csv", "_summary.json");
+        write_sweep_summary(
+            cfg, cfg_text, out_path, sweep, total, converged, conv_rate, wall_time_ms,
+            Some(WarmStartStats {
+                total_iterations: total_iters,
+                cold_baseline_per_cell: cold_baseline,
+                iterations_saved: iters_saved,
+                savings_pct,
+                cold_fallbacks: warm_fallbacks,
+            }),
+            None,
+        )?;
+    }
 
-        #[derive(Serialize)]
-        struct SweepSummary {
-            manifest: Manifest,
-            total_runs: usize,
-            converged_runs: usize,
-            convergence_rate: f64,
-            wall_time_ms: f64,
-            parameter_ranges: ParameterRanges,
+    Ok(())
+}
+
+/// Design-of-experiments sweep (v2.36): instead of the dense BPR×OPR grid, draw
+/// `n_samples` space-filling points over the BPR/OPR ranges via Latin hypercube
+/// or a Sobol sequence, solve each (cold), and emit one row per sample. This
+/// produces exploration/training datasets for surrogate modelling. Warm-starting
+/// and the resumable sidecar are grid-only and intentionally not used here.
+fn run_sweep_doe(
+    cfg: &config::Root,
+    cfg_text: &str,
+    out_path: &str,
+    sweep: &config::Sweep,
+    json_output: bool,
+    threads: usize,
+    format: OutputFormat,
+) -> Result<()> {
+    let n = sweep.n_samples.context("sweep.n_samples required for space-filling sampling")?;
+
+    // Draw unit-cube points: dimension 0 -> bpr, dimension 1 -> opr.
+    let unit: Vec<Vec<f64>> = match sweep.mode.as_str() {
+        "lhs" => {
+            let mut rng = uncertainty::Lcg::new(sweep.seed.unwrap_or(42));
+            uncertainty::latin_hypercube(n, 2, &mut rng)
         }
+        "sobol" => uncertainty::sobol_sequence(n, 2),
+        other => anyhow::bail!("unknown sampling mode '{}'", other),
+    };
 
-        #[derive(Serialize)]
-        struct ParameterRanges {
-            bpr: (f64, f64, usize),
-            opr: (f64, f64, usize),
+    let map = |lo: f64, hi: f64, u: f64| lo + u * (hi - lo);
+    let points: Vec<(f64, f64)> = unit
+        .iter()
+        .map(|p| (map(sweep.bpr_min, sweep.bpr_max, p[0]), map(sweep.opr_min, sweep.opr_max, p[1])))
+        .collect();
+
+    let columnar = format.is_columnar();
+    let mut w = if columnar { None } else { Some(io::CsvWriter::create(out_path)?) };
+    if let Some(w) = w.as_mut() {
+        w.write_header()?;
+    }
+    let mut arrow = if columnar {
+        Some(io::ArrowWriter::new(manifest_metadata(&create_manifest(cfg, cfg_text)))?)
+    } else {
+        None
+    };
+
+    info!("starting {} sampling: {} points", sweep.mode, n);
+
+    let start = Instant::now();
+    // Each sample is an independent cold solve, so evaluate across workers; the
+    // index-stable parallel map keeps the output order identical to the serial
+    // path for any thread count.
+    let solved = parallel::par_map(&points, threads, |_, &(bpr, opr)| {
+        solve_traced(create_input(cfg, bpr, opr))
+    });
+    let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let mut converged = 0;
+    for (i, (&(bpr, opr), out)) in points.iter().zip(&solved).enumerate() {
+        let case = format!("sample_{:04}", i);
+        if let Some(w) = w.as_mut() {
+            w.write_row(&case, bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, out)?;
         }
+        if let Some(arrow) = arrow.as_mut() {
+            arrow.push(io::ResultRow::from_output(&case, bpr, opr, cfg.cycle.mach, cfg.cycle.alt_km, out));
+        }
+        if out.status == 0 {
+            converged += 1;
+        }
+    }
 
-        let summary = SweepSummary {
-            manifest: create_manifest(cfg, cfg_text),
-            total_runs: total,
-            converged_runs: converged,
-            convergence_rate: converged as f64 / total as f64,
-            wall_time_ms,
-            parameter_ranges: ParameterRanges {
-                bpr: (sweep.bpr_min, sweep.bpr_max, sweep.bpr_n),
-                opr: (sweep.opr_min, sweep.opr_max, sweep.opr_n),
-            },
-        };
+    if let Some(mut w) = w {
+        w.flush()?;
+    }
+    if let Some(arrow) = arrow {
+        let parquet_path = out_path.replace(".csv", &format!(".{}", format.extension()));
+        arrow.finish(&parquet_path)?;
+        info!("columnar output: {}", parquet_path);
+    }
 
-        let json = serde_json::to_string_pretty(&summary)?;
-        fs::write(&json_path, json)?;
-        eprintln!("[minotaur] JSON summary: {}", json_path);
+    let total = points.len();
+    let conv_rate = if total > 0 { converged as f64 / total as f64 } else { 0.0 };
+    eprintln!(
+        "[minotaur] {} sampling complete: {}/{} converged ({:.1}%) in {:.1}ms",
+        sweep.mode, converged, total, 100.0 * conv_rate, wall_time_ms
+    );
+
+    if json_output {
+        write_sweep_summary(
+            cfg, cfg_text, out_path, sweep, total, converged, conv_rate, wall_time_ms,
+            None,
+            if threads > 1 { Some((threads, wall_time_ms)) } else { None },
+        )?;
     }
 
     Ok(())
@@ -526,7 +1598,7 @@ fn run_sensitivity(cfg: &config::Root, out_path: &str, step: f64) -> Result<()>
     let outputs = ["tsfc_proxy", "thrust_proxy", "t4", "iterations"];
 
     let inp_base = create_input(cfg, bpr, opr);
-    let out_base = ffi::solve(inp_base);
+    let out_base = solve_traced(inp_base);
 
     if out_base.status != 0 {
         anyhow::bail!("Base configuration does not converge (status={})", out_base.status);
@@ -592,8 +1664,8 @@ fn run_sensitivity(cfg: &config::Root, out_path: &str, step: f64) -> Result<()>
             _ => continue,
         };
 
-        let out_plus = ffi::solve(perturbed_inp_plus);
-        let out_minus = ffi::solve(perturbed_inp_minus);
+        let out_plus = solve_traced(perturbed_inp_plus);
+        let out_minus = solve_traced(perturbed_inp_minus);
 
         let h = step_sizes[*param];
         let two_h = 2.0 * h;
@@ -626,9 +1698,9 @@ fn run_sensitivity(cfg: &config::Root, out_path: &str, step: f64) -> Result<()>
     let json = serde_json::to_string_pretty(&sens)?;
     fs::write(&json_path, json)?;
 
-    eprintln!("[minotaur] sensitivity analysis complete");
-    eprintln!("[minotaur] CSV: {}", out_path);
-    eprintln!("[minotaur] JSON: {}", json_path);
+    info!("sensitivity analysis complete");
+    info!("CSV: {}", out_path);
+    info!("JSON: {}", json_path);
 
     Ok(())
 }
@@ -647,19 +1719,19 @@ fn run_compare(cfg: &config::Root, cfg_text: &str, out_path: &str, level: &str,
         _ => anyhow::bail!("Unknown degradation level: {}. Use light, moderate, severe, or custom", level),
     };
 
-    // Create nominal input
-    let inp_nominal = create_input_ext(cfg, bpr, opr);
+    // Create nominal input. Build it from a degradation-free config clone so a
+    // trajectory declared in [degradation] does not pre-degrade the baseline.
+    let mut nominal_cfg = cfg.clone();
+    nominal_cfg.degradation = None;
+    let inp_nominal = create_input_ext(&nominal_cfg, bpr, opr);
 
-    // Create degraded input
-    let inp_degraded = inp_nominal.with_degradation(
-        degradation.eta_comp_factor,
-        degradation.eta_turb_factor,
-        degradation.loss_adder,
-    );
+    // Resolve the effective factors/adder (trajectory-aware) for the degraded run.
+    let (deg_eta_comp, deg_eta_turb, deg_loss) = degradation.effective();
+    let inp_degraded = inp_nominal.with_degradation(deg_eta_comp, deg_eta_turb, deg_loss);
 
     let start = Instant::now();
-    let out_nominal = ffi::solve_ext(inp_nominal);
-    let out_degraded = ffi::solve_ext(inp_degraded);
+    let out_nominal = solve_ext_traced(inp_nominal);
+    let out_degraded = solve_ext_traced(inp_degraded);
     let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
     // Calculate deltas
@@ -698,8 +1770,8 @@ fn run_compare(cfg: &config::Root, cfg_text: &str, out_path: &str, level: &str,
              out_degraded.t4,
              out_degraded.tsfc_proxy,
              out_degraded.thrust_proxy,
-             cfg.cycle.eta_comp * degradation.eta_comp_factor,
-             cfg.cycle.eta_turb * degradation.eta_turb_factor)?;
+             cfg.cycle.eta_comp * deg_eta_comp,
+             cfg.cycle.eta_turb * deg_eta_turb)?;
     writeln!(file, "")?;
     writeln!(file, "# Delta metrics")?;
     writeln!(file, "# TSFC change: {:.2}%", tsfc_change_pct)?;
@@ -707,7 +1779,7 @@ fn run_compare(cfg: &config::Root, cfg_text: &str, out_path: &str, level: &str,
     writeln!(file, "# T4 change: {:.1} K", t4_change_k)?;
     writeln!(file, "# Iteration change: {}", iter_change)?;
 
-    eprintln!("[minotaur] comparison complete ({} degradation)", level);
+    info!("comparison complete ({} degradation)", level);
     eprintln!("  Nominal:  status={} iter={} t4={:.1} tsfc={:.4} thrust={:.4}",
               out_nominal.status, out_nominal.iter, out_nominal.t4,
               out_nominal.tsfc_proxy, out_nominal.thrust_proxy);
@@ -742,8 +1814,8 @@ fn run_compare(cfg: &config::Root, cfg_text: &str, out_path: &str, level: &str,
                 t4: out_degraded.t4,
                 tsfc_proxy: out_degraded.tsfc_proxy,
                 thrust_proxy: out_degraded.thrust_proxy,
-                eta_comp_effective: cfg.cycle.eta_comp * degradation.eta_comp_factor,
-                eta_turb_effective: cfg.cycle.eta_turb * degradation.eta_turb_factor,
+                eta_comp_effective: cfg.cycle.eta_comp * deg_eta_comp,
+                eta_turb_effective: cfg.cycle.eta_turb * deg_eta_turb,
             },
             delta: DeltaMetrics {
                 tsfc_change_pct,
@@ -754,7 +1826,7 @@ fn run_compare(cfg: &config::Root, cfg_text: &str, out_path: &str, level: &str,
         };
         let json = serde_json::to_string_pretty(&comparison)?;
         fs::write(&json_path, json)?;
-        eprintln!("[minotaur] JSON comparison: {}", json_path);
+        info!("JSON comparison: {}", json_path);
     }
 
     Ok(())
@@ -769,17 +1841,34 @@ fn validate_config(cfg_path: &str) -> Result<()> {
 
     cfg.validate()?;
 
-    eprintln!("[minotaur] config valid: {}", cfg_path);
+    info!("config valid: {}", cfg_path);
     eprintln!("  program: {} v{}", cfg.CSTNSystems.program, cfg.CSTNSystems.version);
     eprintln!("  solver: max_iter={}, tol={:.0e}, damping={}",
               cfg.solver.max_iter, cfg.solver.tol, cfg.solver.damping);
+    if cfg.solver.strategy != "damped" || cfg.solver.min_iter > 0 {
+        eprint!("  strategy: {}, min_iter={}", cfg.solver.strategy, cfg.solver.min_iter);
+        if let Some(n) = cfg.solver.continuation_steps {
+            eprint!(", continuation_steps={}", n);
+        }
+        eprintln!();
+    }
+    if let Some(floors) = &cfg.solver.floors {
+        eprintln!("  floors: pressure={:.1e}, temperature={:.1e}, mass_fraction={:.1e}",
+                  floors.pressure, floors.temperature, floors.mass_fraction);
+    }
     eprintln!("  cycle: mach={}, alt_km={}, bpr={:?}, opr={:?}",
               cfg.cycle.mach, cfg.cycle.alt_km, cfg.cycle.bpr, cfg.cycle.opr);
 
     if let Some(sweep) = &cfg.sweep {
-        eprintln!("  sweep: bpr=[{},{}]×{}, opr=[{},{}]×{}",
-                  sweep.bpr_min, sweep.bpr_max, sweep.bpr_n,
-                  sweep.opr_min, sweep.opr_max, sweep.opr_n);
+        if sweep.mode == "grid" {
+            eprintln!("  sweep: grid bpr=[{},{}]×{}, opr=[{},{}]×{}",
+                      sweep.bpr_min, sweep.bpr_max, sweep.bpr_n,
+                      sweep.opr_min, sweep.opr_max, sweep.opr_n);
+        } else {
+            eprintln!("  sweep: {} sampling, {} point(s) over bpr=[{},{}], opr=[{},{}]",
+                      sweep.mode, sweep.n_samples.unwrap_or(0),
+                      sweep.bpr_min, sweep.bpr_max, sweep.opr_min, sweep.opr_max);
+        }
     }
 
     if let Some(comp) = &cfg.components {
@@ -793,13 +1882,102 @@ fn validate_config(cfg_path: &str) -> Result<()> {
     }
 
     if let Some(deg) = &cfg.degradation {
-        eprintln!("  degradation: eta_comp_factor={}, eta_turb_factor={}, loss_adder={}",
-                  deg.eta_comp_factor, deg.eta_turb_factor, deg.loss_adder);
+        if deg.is_trajectory() {
+            let (ec, et, la) = deg.effective();
+            eprintln!("  degradation: {} trajectory over {:.0} h -> eta_comp_factor={:.4}, eta_turb_factor={:.4}, loss_adder={:.4}",
+                      deg.model, deg.hours.unwrap_or(0.0), ec, et, la);
+        } else {
+            eprintln!("  degradation: eta_comp_factor={}, eta_turb_factor={}, loss_adder={}",
+                      deg.eta_comp_factor, deg.eta_turb_factor, deg.loss_adder);
+        }
+    }
+
+    if let Some(tm) = &cfg.turbomachinery {
+        eprintln!("  turbomachinery: {} compressor stage(s), {} turbine stage(s){}",
+                  tm.compressor.len(), tm.turbine.len(),
+                  if tm.two_spool() {
+                      format!(", two-spool (LP={}, HP={})",
+                              tm.lp_compressor_stages,
+                              tm.compressor.len() - tm.lp_compressor_stages)
+                  } else {
+                      ", single-spool".to_string()
+                  });
+        for (i, s) in tm.compressor.iter().enumerate() {
+            eprintln!("    C{}: pr={:.3}, eta_poly={:.3}", i + 1, s.pressure_ratio, s.polytropic_eff);
+        }
+        for (i, s) in tm.turbine.iter().enumerate() {
+            eprintln!("    T{}: pr={:.3}, eta_poly={:.3}", i + 1, s.pressure_ratio, s.polytropic_eff);
+        }
+        eprintln!("    derived: opr={:.3}, eta_comp={:.4}, eta_turb={:.4}",
+                  tm.overall_opr(), tm.aggregate_eta_comp(), tm.aggregate_eta_turb());
+    }
+
+    if let Some(mission) = &cfg.mission {
+        let total: f64 = mission.iter().map(|s| s.duration_s).sum();
+        eprintln!("  mission: {} segment(s), {:.1} s total", mission.len(), total);
+    }
+
+    if let Some(b) = &cfg.bottoming {
+        eprintln!("  bottoming: hrsg_eff={}, steam_eta={}, pinch_dt={} K, stack={} K{}",
+                  b.hrsg_effectiveness, b.steam_turbine_eta, b.pinch_dt_k, b.stack_temp_k,
+                  if b.back_pressure { ", back-pressure" } else { "" });
+    }
+
+    if let Some(fuel) = &cfg.fuel {
+        if let Some((lhv, far_stoich)) = fuel.resolve() {
+            eprintln!("  fuel: {}, LHV={:.1} MJ/kg, far_stoich={:.3}, phi@t4_max={:.3}",
+                      fuel.fuel_type, lhv, far_stoich,
+                      fuel.equivalence_ratio(cfg.constraints.t4_max));
+        }
     }
 
     Ok(())
 }
 
+/// A resolved optimisation objective: the metric to read off the solver output,
+/// the sign that maps it into NSGA-II's minimisation space (`+1` minimise,
+/// `-1` maximise), and a human-readable label. (v2.29)
+struct ObjSpec {
+    metric: String,
+    sign: f64,
+    label: String,
+}
+
+/// Resolve the configured objective list, falling back to the historical
+/// bi-objective TSFC/thrust trade-off when no `[[objectives]]` are declared.
+fn build_objectives(cfg: &config::Root) -> Vec<ObjSpec> {
+    let declared = cfg.objectives.clone().unwrap_or_else(|| {
+        vec![
+            config::Objective { metric: "tsfc".to_string(), sense: None },
+            config::Objective { metric: "thrust".to_string(), sense: None },
+        ]
+    });
+    declared
+        .into_iter()
+        .map(|o| {
+            let sense = o.sense().to_string();
+            let sign = if sense == "max" { -1.0 } else { 1.0 };
+            ObjSpec {
+                label: format!("{} {}", if sense == "max" { "maximize" } else { "minimize" }, o.metric),
+                metric: o.metric,
+                sign,
+            }
+        })
+        .collect()
+}
+
+/// Read a named metric off a solver output. `fuel_burn` is the fuel-flow proxy
+/// TSFC × thrust (specific consumption times thrust).
+fn metric_value(out: &ffi::MinotaurOutput, metric: &str) -> f64 {
+    match metric {
+        "tsfc" => out.tsfc_proxy,
+        "thrust" => out.thrust_proxy,
+        "t4" => out.t4,
+        "fuel_burn" => out.tsfc_proxy * out.thrust_proxy,
+        _ => 0.0,
+    }
+}
+
 // v2.9: Run multi-objective optimization via NSGA-II
 fn run_optimize(
     cfg: &config::Root,
@@ -808,9 +1986,19 @@ fn run_optimize(
     pop_size: usize,
     generations: usize,
     seed: u64,
+    local_search_prob: f64,
+    rng: RngChoice,
+    mutation: MutationChoice,
+    history: Option<&str>,
+    threads: usize,
     json_output: bool,
+    format: OutputFormat,
 ) -> Result<()> {
-    use nsga2::{NSGA2, NSGA2Config, hypervolume_2d};
+    use nsga2::{NSGA2, NSGA2Config, hypervolume};
+
+    // Resolve the objective list (defaults to the TSFC/thrust trade-off).
+    let objectives = build_objectives(cfg);
+    let n_obj = objectives.len();
 
     // Configure optimizer
     let nsga_config = NSGA2Config {
@@ -827,14 +2015,23 @@ fn run_optimize(
             (0.80, 0.92),                        // eta_turb
         ],
         seed,
+        reg_mode: nsga2::RegularizationMode::Hard,
+        local_search_prob,
+        local_search_step: 0.1,
+        local_search_budget: 20,
+        threads,
+        rng_kind: rng.kind(),
+        mutation_kind: mutation.kind(),
+        ..Default::default()
     };
 
     let mach = cfg.cycle.mach;
     let alt_km = cfg.cycle.alt_km;
     let t4_max = cfg.constraints.t4_max;
 
-    eprintln!("[minotaur] starting NSGA-II optimization");
-    eprintln!("  Population: {}, Generations: {}, Seed: {}", pop_size, generations, seed);
+    info!("starting NSGA-II optimization");
+    eprintln!("  Population: {}, Generations: {}, Seed: {}, Threads: {}", pop_size, generations, seed, threads);
+    eprintln!("  Objectives: {}", objectives.iter().map(|o| o.label.clone()).collect::<Vec<_>>().join(", "));
 
     let start = Instant::now();
 
@@ -857,48 +2054,149 @@ fn run_optimize(
             mass_tol: cfg.invariants.mass_tol,
             energy_tol: cfg.invariants.energy_tol,
             t4_max,
+            globalize: cfg.solver.globalize_id(),
+            lm_lambda_init: cfg.solver.lm_lambda_init,
+            lm_lambda_up: cfg.solver.lm_lambda_up,
+            lm_lambda_down: cfg.solver.lm_lambda_down,
+            lm_lambda_cap: cfg.solver.lm_lambda_cap,
+            jac_reuse: if cfg.solver.jac_reuse { 1 } else { 0 },
+            jac_refresh_interval: cfg.solver.jac_refresh_interval,
+            jac_stagnation_factor: cfg.solver.jac_stagnation_factor,
+            term_mode: termination::TerminationMode::parse(&cfg.solver.term_mode)
+                .map(|m| m.id())
+                .unwrap_or(0),
+            abstol: cfg.solver.abstol.unwrap_or(cfg.solver.tol),
+            reltol: cfg.solver.reltol.unwrap_or((cfg.solver.tol * 1e3).min(1e-6)),
+            stol: cfg.solver.stol.unwrap_or(cfg.solver.tol),
+            warm_start: 0,
+            warm_bpr: 0.0,
+            warm_opr: 0.0,
+            warm_t4: 0.0,
+            floor_pressure: cfg.solver.floors.as_ref().map(|f| f.pressure).unwrap_or(0.0),
+            floor_temp: cfg.solver.floors.as_ref().map(|f| f.temperature).unwrap_or(0.0),
+            floor_massfrac: cfg.solver.floors.as_ref().map(|f| f.mass_fraction).unwrap_or(0.0),
+            // NSGA-II optimises the lumped decision variables directly, so the
+            // stage stack stays collapsed here (v2.28).
+            n_comp_stages: 0,
+            n_turb_stages: 0,
+            lp_comp_stages: 0,
         };
 
-        let out = ffi::solve(inp);
+        let out = solve_traced(inp);
+
+        // Physical metrics carried alongside the objective vector so the
+        // reporting and columnar paths always have TSFC/thrust/T4 regardless of
+        // which metrics were chosen as objectives: [t4, iter, tsfc, thrust].
+        let outputs = vec![out.t4, out.iter as f64, out.tsfc_proxy, out.thrust_proxy];
 
         if out.status != 0 {
-            // Penalize non-converged solutions
-            (vec![1e6, 1e6], 1.0, out.status, vec![out.t4, out.iter as f64])
+            // Penalize non-converged solutions across every objective.
+            (vec![1e6; n_obj], 1.0, out.status, outputs)
         } else {
-            // Objectives: minimize TSFC, minimize -thrust (i.e., maximize thrust)
+            // Map each configured metric into minimisation space via its sign.
+            let f: Vec<f64> = objectives
+                .iter()
+                .map(|o| o.sign * metric_value(&out, &o.metric))
+                .collect();
             let cv = if out.t4 > t4_max { out.t4 - t4_max } else { 0.0 };
-            (vec![out.tsfc_proxy, -out.thrust_proxy], cv, out.status, vec![out.t4, out.iter as f64])
+            (f, cv, out.status, outputs)
         }
     };
 
-    let front = optimizer.optimize(eval_fn);
+    // Optionally log a per-generation convergence trajectory to CSV. The
+    // callback reads TSFC/thrust from each individual's outputs block
+    // ([t4, iter, tsfc, thrust]) and the constraint violation off `cv`.
+    let front = if let Some(history_path) = history {
+        let mut hist = io::CsvWriter::create(history_path)?;
+        hist.write_generation_header()?;
+        let front = optimizer.optimize_with(eval_fn, |gen, front, population| {
+            let hv = front.hypervolume.unwrap_or(0.0);
+            let mut best_tsfc = f64::INFINITY;
+            let mut best_thrust = f64::NEG_INFINITY;
+            let mut cv_sum = 0.0;
+            for ind in population {
+                if ind.outputs.len() >= 4 {
+                    best_tsfc = best_tsfc.min(ind.outputs[2]);
+                    best_thrust = best_thrust.max(ind.outputs[3]);
+                }
+                cv_sum += ind.cv;
+            }
+            let mean_cv = if population.is_empty() { 0.0 } else { cv_sum / population.len() as f64 };
+            let _ = hist.write_generation_row(gen, hv, front.solutions.len(), best_tsfc, best_thrust, mean_cv);
+        });
+        hist.flush()?;
+        front
+    } else {
+        optimizer.optimize(eval_fn)
+    };
+    let local_search_evals = optimizer.local_search_evals();
     let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
 
-    eprintln!("[minotaur] optimization complete");
+    info!("optimization complete");
     eprintln!("  Pareto front size: {}", front.solutions.len());
     eprintln!("  Wall time: {:.1} ms", wall_time_ms);
-
-    // Compute hypervolume
-    let ref_point = (2.0, 0.0); // Reference point for hypervolume (max TSFC, min -thrust)
-    let hv = hypervolume_2d(&front.solutions, ref_point);
-    eprintln!("  Hypervolume (2D): {:.4}", hv);
-
-    // Write CSV
-    let mut file = fs::File::create(out_path)?;
-    writeln!(file, "rank,crowding,bpr,opr,eta_comp,eta_turb,tsfc,thrust,t4,status")?;
-    for sol in &front.solutions {
-        writeln!(file, "{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.6},{:.6},{:.2},{}",
-                 sol.rank,
-                 if sol.crowding_distance.is_infinite() { -1.0 } else { sol.crowding_distance },
-                 sol.x[0], sol.x[1], sol.x[2], sol.x[3],
-                 sol.f[0], -sol.f[1],  // Convert back to positive thrust
-                 sol.outputs[0],
-                 sol.status)?;
+    if local_search_prob > 0.0 {
+        eprintln!("  Local-search evaluations: {}", local_search_evals);
     }
 
-    eprintln!("[minotaur] Pareto front written to: {}", out_path);
+    // `optimize` fills the hypervolume against an auto-derived reference point;
+    // fall back to computing it here for any front that left it unset.
+    let hv = front.hypervolume.unwrap_or_else(|| {
+        let ref_point = nsga2::auto_reference_point(&front.solutions);
+        hypervolume(&front.solutions, &ref_point)
+    });
+    eprintln!("  Hypervolume ({}D): {:.4}", n_obj, hv);
+
+    // Natural-sense value of objective `m` for a solution (undo the sign used to
+    // fold maximisation into minimisation space).
+    let natural = |sol: &nsga2::Individual, m: usize| objectives[m].sign * sol.f[m];
+
+    // Write front: CSV (default) or columnar Parquet mirroring the sweep path.
+    // The columnar path keeps its fixed TSFC/thrust/T4 schema (read from the
+    // physical-metric outputs), while the CSV path emits one column per
+    // configured objective.
+    let written_path = if format.is_columnar() {
+        let parquet_path = out_path.replace(".csv", &format!(".{}", format.extension()));
+        let mut aw = io::ArrowParetoWriter::new(manifest_metadata(&create_manifest(cfg, cfg_text)))?;
+        for sol in &front.solutions {
+            aw.push(io::ParetoRow {
+                rank: sol.rank as i32,
+                crowding: if sol.crowding_distance.is_infinite() { -1.0 } else { sol.crowding_distance },
+                bpr: sol.x[0],
+                opr: sol.x[1],
+                eta_comp: sol.x[2],
+                eta_turb: sol.x[3],
+                tsfc: sol.outputs[2],
+                thrust: sol.outputs[3],
+                t4: sol.outputs[0],
+                status: sol.status,
+            });
+        }
+        aw.finish(&parquet_path)?;
+        parquet_path
+    } else {
+        let mut file = fs::File::create(out_path)?;
+        write!(file, "rank,crowding,bpr,opr,eta_comp,eta_turb")?;
+        for o in &objectives {
+            write!(file, ",{}", o.metric)?;
+        }
+        writeln!(file, ",t4,status")?;
+        for sol in &front.solutions {
+            write!(file, "{},{:.4},{:.4},{:.4},{:.4},{:.4}",
+                   sol.rank,
+                   if sol.crowding_distance.is_infinite() { -1.0 } else { sol.crowding_distance },
+                   sol.x[0], sol.x[1], sol.x[2], sol.x[3])?;
+            for m in 0..n_obj {
+                write!(file, ",{:.6}", natural(sol, m))?;
+            }
+            writeln!(file, ",{:.2},{}", sol.outputs[0], sol.status)?;
+        }
+        out_path.to_string()
+    };
+
+    info!("Pareto front written to: {}", written_path);
 
-    // Print top solutions
+    // Print top solutions (ordered by the first objective).
     eprintln!();
     eprintln!("  Top Pareto solutions:");
     eprintln!("  {:>6} {:>6} {:>8} {:>8} {:>10} {:>10} {:>8}",
@@ -908,10 +2206,10 @@ fn run_optimize(
     let mut sorted = front.solutions.clone();
     sorted.sort_by(|a, b| a.f[0].partial_cmp(&b.f[0]).unwrap_or(std::cmp::Ordering::Equal));
 
-    for (i, sol) in sorted.iter().take(10).enumerate() {
+    for sol in sorted.iter().take(10) {
         eprintln!("  {:>6.3} {:>6.2} {:>8.4} {:>8.4} {:>10.6} {:>10.6} {:>8.1}",
                   sol.x[0], sol.x[1], sol.x[2], sol.x[3],
-                  sol.f[0], -sol.f[1], sol.outputs[0]);
+                  sol.outputs[2], sol.outputs[3], sol.outputs[0]);
     }
 
     if json_output {
@@ -923,9 +2221,10 @@ fn run_optimize(
                 opr: sol.x[1],
                 eta_comp: sol.x[2],
                 eta_turb: sol.x[3],
-                tsfc: sol.f[0],
-                thrust: -sol.f[1],
+                tsfc: sol.outputs[2],
+                thrust: sol.outputs[3],
                 t4: sol.outputs[0],
+                objectives: (0..n_obj).map(|m| natural(sol, m)).collect(),
                 status: sol.status,
                 rank: sol.rank,
                 crowding_distance: sol.crowding_distance,
@@ -941,17 +2240,277 @@ fn run_optimize(
                 mutation_prob: nsga_config.mutation_prob,
                 seed: nsga_config.seed,
                 bounds: nsga_config.bounds,
-                objectives: vec!["minimize TSFC".to_string(), "maximize Thrust".to_string()],
+                objectives: objectives.iter().map(|o| o.label.clone()).collect(),
             },
             pareto_front: pareto_solutions,
             hypervolume: Some(hv),
             generations: front.generation,
             wall_time_ms,
+            local_search_evals,
         };
 
         let json = serde_json::to_string_pretty(&opt_output)?;
         fs::write(&json_path, json)?;
-        eprintln!("[minotaur] JSON optimization results: {}", json_path);
+        info!("JSON optimization results: {}", json_path);
+    }
+
+    Ok(())
+}
+
+// v2.19: Apply a sampled value to the named uncertain input on a config clone.
+fn apply_uncertain_param(cfg: &mut config::Root, name: &str, value: f64) {
+    match name {
+        "eta_comp" => cfg.cycle.eta_comp = value,
+        "eta_turb" => cfg.cycle.eta_turb = value,
+        "bpr" => cfg.cycle.bpr = Some(value),
+        "opr" => cfg.cycle.opr = Some(value),
+        "losses.inlet" => cfg.losses.get_or_insert_with(Default::default).inlet = value,
+        "losses.burner" => cfg.losses.get_or_insert_with(Default::default).burner = value,
+        "losses.turbine" => cfg.losses.get_or_insert_with(Default::default).turbine = value,
+        "losses.nozzle" => cfg.losses.get_or_insert_with(Default::default).nozzle = value,
+        _ => {}
+    }
+}
+
+// v2.19: Monte Carlo uncertainty propagation
+fn run_uncertainty(cfg: &config::Root, cfg_text: &str, out_path: &str, samples_override: Option<usize>) -> Result<()> {
+    let uq = cfg.uncertainty.as_ref().context("[uncertainty] section required for uncertainty mode")?;
+    let bpr = cfg.cycle.bpr.context("cycle.bpr required")?;
+    let opr = cfg.cycle.opr.context("cycle.opr required")?;
+
+    let n = samples_override.unwrap_or(uq.samples);
+    let k = uq.parameters.len();
+
+    info!("Monte Carlo uncertainty: {} samples over {} parameters", n, k);
+
+    // Latin-hypercube draws in [0,1)^k, then invert each parameter's CDF.
+    let mut rng = uncertainty::Lcg::new(uq.seed);
+    let unit = uncertainty::latin_hypercube(n, k, &mut rng);
+
+    let param_names: Vec<String> = uq.parameters.iter().map(|p| p.name.clone()).collect();
+
+    // Raw-sample CSV.
+    let mut file = fs::File::create(out_path)?;
+    write!(file, "sample,status,converged")?;
+    for name in &param_names {
+        write!(file, ",{}", name)?;
+    }
+    writeln!(file, ",tsfc_proxy,thrust_proxy,t4")?;
+
+    let mut tsfc: Vec<f64> = Vec::new();
+    let mut thrust: Vec<f64> = Vec::new();
+    let mut t4: Vec<f64> = Vec::new();
+    let mut converged = 0;
+
+    let start = Instant::now();
+    for (i, row) in unit.iter().enumerate() {
+        let mut sample_cfg = cfg.clone();
+        let mut values = Vec::with_capacity(k);
+        for (j, p) in uq.parameters.iter().enumerate() {
+            let v = p.invert(row[j]);
+            apply_uncertain_param(&mut sample_cfg, &p.name, v);
+            values.push(v);
+        }
+
+        let sbpr = sample_cfg.cycle.bpr.unwrap_or(bpr);
+        let sopr = sample_cfg.cycle.opr.unwrap_or(opr);
+        let ext = create_input_ext(&sample_cfg, sbpr, sopr);
+        let out = solve_ext_traced(ext);
+
+        write!(file, "{},{},{}", i, out.status, out.status == 0)?;
+        for v in &values {
+            write!(file, ",{:.8}", v)?;
+        }
+        writeln!(file, ",{:.6},{:.6},{:.2}", out.tsfc_proxy, out.thrust_proxy, out.t4)?;
+
+        // Non-converged runs are recorded in the CSV but excluded from statistics.
+        if out.status == 0 {
+            converged += 1;
+            tsfc.push(out.tsfc_proxy);
+            thrust.push(out.thrust_proxy);
+            t4.push(out.t4);
+        }
+    }
+    let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    eprintln!(
+        "[minotaur] uncertainty complete: {}/{} converged ({:.1}%) in {:.1}ms",
+        converged, n, 100.0 * converged as f64 / n as f64, wall_time_ms
+    );
+
+    if converged == 0 {
+        anyhow::bail!("no samples converged; cannot summarize output distributions");
+    }
+
+    let mut outputs = HashMap::new();
+    outputs.insert("tsfc_proxy".to_string(), uncertainty::summarize(&tsfc, uq.cdf_points).into());
+    outputs.insert("thrust_proxy".to_string(), uncertainty::summarize(&thrust, uq.cdf_points).into());
+    outputs.insert("t4".to_string(), uncertainty::summarize(&t4, uq.cdf_points).into());
+
+    for (name, s) in [("tsfc_proxy", &tsfc), ("thrust_proxy", &thrust), ("t4", &t4)] {
+        let stats = uncertainty::summarize(s, uq.cdf_points);
+        eprintln!("  {:<12} P5={:.4} P50={:.4} P95={:.4} mean={:.4} std={:.4}",
+                  name, stats.p5, stats.p50, stats.p95, stats.mean, stats.std);
+    }
+
+    let json_path = out_path.replace(".csv", "_summary.json");
+    let summary = UncertaintyOutput {
+        manifest: create_manifest(cfg, cfg_text),
+        samples_requested: n,
+        samples_converged: converged,
+        parameters: param_names,
+        outputs,
+    };
+    let json = serde_json::to_string_pretty(&summary)?;
+    fs::write(&json_path, json)?;
+    info!("raw samples: {}", out_path);
+    info!("JSON summary: {}", json_path);
+
+    Ok(())
+}
+
+// v2.33: Per-segment and aggregate JSON output for a mission run.
+#[derive(Serialize)]
+struct MissionSegmentResult {
+    index: usize,
+    mach: f64,
+    alt_km: f64,
+    throttle: f64,
+    duration_s: f64,
+    bpr: f64,
+    opr: f64,
+    status: i32,
+    status_name: String,
+    converged: bool,
+    iterations: i32,
+    t4: f64,
+    tsfc_proxy: f64,
+    thrust_proxy: f64,
+    fuel_burn: f64,
+}
+
+#[derive(Serialize)]
+struct MissionOutput {
+    manifest: Manifest,
+    segments: Vec<MissionSegmentResult>,
+    total_duration_s: f64,
+    total_fuel_burn: f64,
+    avg_tsfc: f64,
+    peak_t4: f64,
+    wall_time_ms: f64,
+}
+
+/// Run a mission-segment schedule (v2.33): solve the cycle at each declared
+/// segment — inheriting the `[cycle]` efficiencies, pressure ratio, and bypass
+/// ratio unless the segment overrides them, and scaling the fuel parameter by
+/// the segment throttle — then aggregate integrated fuel burn
+/// (`Σ tsfc·thrust·duration`), time-weighted average TSFC, and peak T4 across
+/// the mission. Per-segment and total results are reported.
+fn run_mission(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
+    let mission = cfg.mission.as_ref().context("[mission] schedule required for mission mode")?;
+
+    let mut file = fs::File::create(out_path)?;
+    writeln!(
+        file,
+        "segment,mach,alt_km,throttle,duration_s,bpr,opr,status,converged,iter,t4,tsfc_proxy,thrust_proxy,fuel_burn"
+    )?;
+
+    let mut segments = Vec::with_capacity(mission.len());
+    let mut total_duration = 0.0;
+    let mut total_fuel = 0.0;
+    let mut tsfc_time_weighted = 0.0;
+    let mut peak_t4 = f64::NEG_INFINITY;
+
+    let start = Instant::now();
+    for (i, seg) in mission.iter().enumerate() {
+        // Each segment inherits the base cycle, overriding only what it declares.
+        let mut seg_cfg = cfg.clone();
+        seg_cfg.cycle.mach = seg.mach;
+        seg_cfg.cycle.alt_km = seg.alt_km;
+        if let Some(v) = seg.eta_comp { seg_cfg.cycle.eta_comp = v; }
+        if let Some(v) = seg.eta_turb { seg_cfg.cycle.eta_turb = v; }
+        if let Some(v) = seg.eta_nozz { seg_cfg.cycle.eta_nozz = v; }
+        seg_cfg.cycle.fuel_k *= seg.throttle;
+
+        let bpr = seg.bpr.or(cfg.cycle.bpr)
+            .with_context(|| format!("mission segment {}: bpr required (set segment or cycle.bpr)", i + 1))?;
+        let opr = seg.opr.or(cfg.cycle.opr)
+            .with_context(|| format!("mission segment {}: opr required (set segment or cycle.opr)", i + 1))?;
+
+        let out = solve_traced(create_input(&seg_cfg, bpr, opr));
+        let fuel_burn = out.tsfc_proxy * out.thrust_proxy * seg.duration_s;
+
+        total_duration += seg.duration_s;
+        total_fuel += fuel_burn;
+        tsfc_time_weighted += out.tsfc_proxy * seg.duration_s;
+        peak_t4 = peak_t4.max(out.t4);
+
+        writeln!(
+            file,
+            "{},{:.4},{:.4},{:.4},{:.1},{:.4},{:.4},{},{},{},{:.2},{:.6},{:.6},{:.6}",
+            i, seg.mach, seg.alt_km, seg.throttle, seg.duration_s, bpr, opr,
+            out.status, out.status == 0, out.iter, out.t4, out.tsfc_proxy, out.thrust_proxy, fuel_burn
+        )?;
+
+        segments.push(MissionSegmentResult {
+            index: i,
+            mach: seg.mach,
+            alt_km: seg.alt_km,
+            throttle: seg.throttle,
+            duration_s: seg.duration_s,
+            bpr,
+            opr,
+            status: out.status,
+            status_name: ffi::status_name(out.status).to_string(),
+            converged: out.status == 0,
+            iterations: out.iter,
+            t4: out.t4,
+            tsfc_proxy: out.tsfc_proxy,
+            thrust_proxy: out.thrust_proxy,
+            fuel_burn,
+        });
+    }
+    let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let avg_tsfc = if total_duration > 0.0 { tsfc_time_weighted / total_duration } else { 0.0 };
+
+    writeln!(file, "")?;
+    writeln!(file, "# Mission totals")?;
+    writeln!(file, "# Total duration: {:.1} s", total_duration)?;
+    writeln!(file, "# Integrated fuel burn: {:.6}", total_fuel)?;
+    writeln!(file, "# Time-weighted TSFC: {:.6}", avg_tsfc)?;
+    writeln!(file, "# Peak T4: {:.1} K", peak_t4)?;
+
+    info!("mission complete: {} segment(s)", mission.len());
+    for s in &segments {
+        eprintln!(
+            "  segment {}: mach={:.3} alt={:.1} status={} ({}) t4={:.1} tsfc={:.4} thrust={:.4} fuel={:.4}",
+            s.index, s.mach, s.alt_km, s.status, s.status_name, s.t4, s.tsfc_proxy, s.thrust_proxy, s.fuel_burn
+        );
+    }
+    eprintln!(
+        "  totals: duration={:.1}s fuel_burn={:.4} avg_tsfc={:.4} peak_t4={:.1}K",
+        total_duration, total_fuel, avg_tsfc, peak_t4
+    );
+
+    if segments.iter().any(|s| !s.converged) {
+        warn!("one or more mission segments did not converge");
+    }
+
+    if json_output {
+        let json_path = out_path.replace(".csv", ".json");
+        let mission_output = MissionOutput {
+            manifest: create_manifest(cfg, cfg_text),
+            segments,
+            total_duration_s: total_duration,
+            total_fuel_burn: total_fuel,
+            avg_tsfc,
+            peak_t4,
+            wall_time_ms,
+        };
+        let json = serde_json::to_string_pretty(&mission_output)?;
+        fs::write(&json_path, json)?;
+        info!("JSON mission bundle: {}", json_path);
     }
 
     Ok(())
@@ -960,7 +2519,16 @@ fn run_optimize(
 // v2.8: Compute exact Jacobian via forward-mode AD
 fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output: bool) -> Result<()> {
     let bpr = cfg.cycle.bpr.context("cycle.bpr required")?;
-    let opr = cfg.cycle.opr.context("cycle.opr required")?;
+    // v2.28: differentiate about the derived aggregates when a stage stack is
+    // declared, otherwise about the lumped cycle inputs.
+    let (opr, eta_comp, eta_turb) = match cfg.turbomachinery {
+        Some(ref tm) => (tm.overall_opr(), tm.aggregate_eta_comp(), tm.aggregate_eta_turb()),
+        None => (
+            cfg.cycle.opr.context("cycle.opr required")?,
+            cfg.cycle.eta_comp,
+            cfg.cycle.eta_turb,
+        ),
+    };
 
     let start = Instant::now();
     let result = ffi::compute_jacobian(
@@ -968,8 +2536,8 @@ fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output:
         cfg.cycle.alt_km,
         bpr,
         opr,
-        cfg.cycle.eta_comp,
-        cfg.cycle.eta_turb,
+        eta_comp,
+        eta_turb,
         cfg.constraints.t4_max,
     );
     let wall_time_ms = start.elapsed().as_secs_f64() * 1000.0;
@@ -982,7 +2550,7 @@ fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output:
                  param, result.jacobian[i][0], result.jacobian[i][1], result.jacobian[i][2])?;
     }
 
-    eprintln!("[minotaur] exact Jacobian computed via forward-mode AD");
+    info!("exact Jacobian computed via forward-mode AD");
     eprintln!("  Base values: TSFC={:.4}, Thrust={:.4}, T4={:.1} K",
               result.base_tsfc, result.base_thrust, result.base_t4);
     eprintln!("  Status: {} ({})", result.status, ffi::status_name(result.status));
@@ -996,6 +2564,50 @@ fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output:
                   param, result.jacobian[i][0], result.jacobian[i][1], result.jacobian[i][2]);
     }
 
+    // v2.28: when a stage stack is declared, project the lumped eta_comp/eta_turb
+    // sensitivities onto the per-stage polytropic efficiencies via the chain rule,
+    // d(out)/d(eta_poly_k) = d(out)/d(eta_agg) · d(eta_agg)/d(eta_poly_k). The
+    // aggregate-efficiency derivatives are taken by central difference on the pure
+    // roll-up, which avoids threading per-stage seeds through the Fortran AD core.
+    if let Some(tm) = &cfg.turbomachinery {
+        const H: f64 = 1e-6;
+        // Rows 4 and 5 of the Jacobian are the lumped eta_comp and eta_turb.
+        let d_eta_comp = result.jacobian[4];
+        let d_eta_turb = result.jacobian[5];
+
+        let comp_deriv = |k: usize| -> f64 {
+            let mut up = tm.clone();
+            let mut dn = tm.clone();
+            up.compressor[k].polytropic_eff += H;
+            dn.compressor[k].polytropic_eff -= H;
+            (up.aggregate_eta_comp() - dn.aggregate_eta_comp()) / (2.0 * H)
+        };
+        let turb_deriv = |k: usize| -> f64 {
+            let mut up = tm.clone();
+            let mut dn = tm.clone();
+            up.turbine[k].polytropic_eff += H;
+            dn.turbine[k].polytropic_eff -= H;
+            (up.aggregate_eta_turb() - dn.aggregate_eta_turb()) / (2.0 * H)
+        };
+
+        eprintln!();
+        eprintln!("  Per-stage polytropic-efficiency sensitivities:");
+        eprintln!("  {:12} {:>14} {:>14} {:>14}", "Stage", "dTSFC", "dThrust", "dT4");
+        eprintln!("  {}", "-".repeat(56));
+        for k in 0..tm.compressor.len() {
+            let g = comp_deriv(k);
+            eprintln!("  {:12} {:>14.6e} {:>14.6e} {:>14.4f}",
+                      format!("C{}.eta", k + 1),
+                      d_eta_comp[0] * g, d_eta_comp[1] * g, d_eta_comp[2] * g);
+        }
+        for k in 0..tm.turbine.len() {
+            let g = turb_deriv(k);
+            eprintln!("  {:12} {:>14.6e} {:>14.6e} {:>14.4f}",
+                      format!("T{}.eta", k + 1),
+                      d_eta_turb[0] * g, d_eta_turb[1] * g, d_eta_turb[2] * g);
+        }
+    }
+
     if json_output {
         let json_path = out_path.replace(".csv", ".json");
         let mut base_values = HashMap::new();
@@ -1006,8 +2618,8 @@ fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output:
         base_values.insert("alt_km".to_string(), cfg.cycle.alt_km);
         base_values.insert("bpr".to_string(), bpr);
         base_values.insert("opr".to_string(), opr);
-        base_values.insert("eta_comp".to_string(), cfg.cycle.eta_comp);
-        base_values.insert("eta_turb".to_string(), cfg.cycle.eta_turb);
+        base_values.insert("eta_comp".to_string(), eta_comp);
+        base_values.insert("eta_turb".to_string(), eta_turb);
 
         let jac_output = JacobianOutput {
             manifest: create_manifest(cfg, cfg_text),
@@ -1020,7 +2632,7 @@ fn run_jacobian(cfg: &config::Root, cfg_text: &str, out_path: &str, json_output:
         };
         let json = serde_json::to_string_pretty(&jac_output)?;
         fs::write(&json_path, json)?;
-        eprintln!("[minotaur] JSON jacobian: {}", json_path);
+        info!("JSON jacobian: {}", json_path);
     }
 
     Ok(())
@@ -1077,6 +2689,8 @@ fn print_version() {
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    logging::init(args.log_level, args.log_format);
+
     match args.command {
         Some(Commands::Version) => {
             print_version();
@@ -1090,10 +2704,10 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
             return run_jacobian(&cfg, &cfg_text, &out_path, json);
         }
-        Some(Commands::Optimize { pop_size, generations, seed, json }) => {
+        Some(Commands::Optimize { pop_size, generations, seed, local_search_prob, rng, mutation, history, json }) => {
             let cfg_path = args.config.context("--config required")?;
             let out_path = args.out.unwrap_or_else(|| "results/pareto_front.csv".to_string());
 
@@ -1101,8 +2715,30 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
-            return run_optimize(&cfg, &cfg_text, &out_path, pop_size, generations, seed, json);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_optimize(&cfg, &cfg_text, &out_path, pop_size, generations, seed, local_search_prob, rng, mutation, history.as_deref(), args.threads, json, args.format);
+        }
+        Some(Commands::Uncertainty { samples }) => {
+            let cfg_path = args.config.context("--config required")?;
+            let out_path = args.out.unwrap_or_else(|| "results/uncertainty_samples.csv".to_string());
+
+            let cfg_text = fs::read_to_string(&cfg_path)?;
+            let cfg: config::Root = toml::from_str(&cfg_text)?;
+            cfg.validate()?;
+
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_uncertainty(&cfg, &cfg_text, &out_path, samples);
+        }
+        Some(Commands::Mission { json }) => {
+            let cfg_path = args.config.context("--config required")?;
+            let out_path = args.out.unwrap_or_else(|| "results/mission.csv".to_string());
+
+            let cfg_text = fs::read_to_string(&cfg_path)?;
+            let cfg: config::Root = toml::from_str(&cfg_text)?;
+            cfg.validate()?;
+
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_mission(&cfg, &cfg_text, &out_path, json);
         }
         Some(Commands::Validate) => {
             let cfg_path = args.config.context("--config required for validate")?;
@@ -1126,7 +2762,7 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
             return run_compare(&cfg, &cfg_text, &out_path, &level, json);
         }
         Some(Commands::Run { json }) => {
@@ -1137,10 +2773,10 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
-            return run_single(&cfg, &cfg_text, &out_path, json);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_single(&cfg, &cfg_text, &out_path, json, args.format);
         }
-        Some(Commands::Sweep { json }) => {
+        Some(Commands::Sweep { json, resume }) => {
             let cfg_path = args.config.context("--config required")?;
             let out_path = args.out.unwrap_or_else(|| "results/out_sweep.csv".to_string());
 
@@ -1148,8 +2784,30 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
-            return run_sweep(&cfg, &cfg_text, &out_path, json);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_sweep(&cfg, &cfg_text, &out_path, json, resume, args.threads, args.format);
+        }
+        Some(Commands::Minimize { json }) => {
+            let cfg_path = args.config.context("--config required")?;
+            let out_path = args.out.unwrap_or_else(|| "results/minimize.csv".to_string());
+
+            let cfg_text = fs::read_to_string(&cfg_path)?;
+            let cfg: config::Root = toml::from_str(&cfg_text)?;
+            cfg.validate()?;
+
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_minimize(&cfg, &cfg_text, &out_path, json);
+        }
+        Some(Commands::Design { json }) => {
+            let cfg_path = args.config.context("--config required")?;
+            let out_path = args.out.unwrap_or_else(|| "results/design.csv".to_string());
+
+            let cfg_text = fs::read_to_string(&cfg_path)?;
+            let cfg: config::Root = toml::from_str(&cfg_text)?;
+            cfg.validate()?;
+
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            return run_design(&cfg, &cfg_text, &out_path, json);
         }
         None => {
             let cfg_path = args.config.context("--config required")?;
@@ -1159,11 +2817,11 @@ fn main() -> Result<()> {
             let cfg: config::Root = toml::from_str(&cfg_text)?;
             cfg.validate()?;
 
-            eprintln!("[minotaur] {} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
+            info!("{} v{} - {}", cfg.CSTNSystems.program, cfg.CSTNSystems.version, cfg.CSTNSystems.module);
 
             match args.mode.as_str() {
-                "single" => run_single(&cfg, &cfg_text, &out_path, false),
-                "sweep" => run_sweep(&cfg, &cfg_text, &out_path, false),
+                "single" => run_single(&cfg, &cfg_text, &out_path, false, args.format),
+                "sweep" => run_sweep(&cfg, &cfg_text, &out_path, false, false, args.threads, args.format),
                 _ => anyhow::bail!("unknown mode: {} (use 'single' or 'sweep')", args.mode),
             }
         }
@@ -8,16 +8,268 @@
 //!
 //! Author: CSTNSystems, EINIX SA
 //! License: LicenseRef-EINIXSA-Internal-Eval
-//! Version: 2.9.0
+//! Version: 2.29.0
 
 use std::cmp::Ordering;
 
+use crate::parallel;
+
+// ============================================================================
+// Pluggable random source
+//
+// The variation operators and LHS initializer draw every decision through an
+// [`RngSource`], so the generator is a swappable strategy rather than an inlined
+// LCG. The default [`Lcg`] reproduces the historical seeded stream bit-for-bit;
+// [`Pcg64`] and [`ChaCha8`] are higher-quality alternatives whose low-order bits
+// do not correlate, which matters on long runs.
+// ============================================================================
+
+/// A deterministic, seedable source of pseudo-random numbers.
+pub trait RngSource {
+    /// Next draw in the half-open unit interval [0, 1).
+    fn next_f64(&mut self) -> f64;
+
+    /// Uniform integer in `[0, n)`. Returns 0 when `n == 0`.
+    fn gen_range(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            ((self.next_f64() * n as f64) as usize).min(n - 1)
+        }
+    }
+}
+
+/// Which generator an [`NSGA2`]/[`Spea2`] run uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RngKind {
+    /// Wrapping linear congruential generator (historical default).
+    Lcg,
+    /// Permuted congruential generator, 128-bit state XSL-RR 64-bit output.
+    Pcg64,
+    /// ChaCha stream cipher reduced to 8 rounds.
+    ChaCha8,
+}
+
+impl Default for RngKind {
+    fn default() -> Self {
+        RngKind::Lcg
+    }
+}
+
+/// Build the selected generator from a seed.
+pub fn make_rng(kind: RngKind, seed: u64) -> Box<dyn RngSource> {
+    match kind {
+        RngKind::Lcg => Box::new(Lcg::new(seed)),
+        RngKind::Pcg64 => Box::new(Pcg64::new(seed)),
+        RngKind::ChaCha8 => Box::new(ChaCha8::new(seed)),
+    }
+}
+
+/// SplitMix64 — used only to diversify seeds into generator state words.
+fn splitmix64(x: &mut u64) -> u64 {
+    *x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *x;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Wrapping linear congruential generator — the historical NSGA-II source.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RngSource for Lcg {
+    fn next_f64(&mut self) -> f64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.state >> 33) as f64) / ((1u64 << 31) as f64)
+    }
+}
+
+/// PCG64 (128-bit LCG state with an XSL-RR 64-bit permutation output).
+pub struct Pcg64 {
+    state: u128,
+    inc: u128,
+}
+
+impl Pcg64 {
+    const MUL: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+
+    pub fn new(seed: u64) -> Self {
+        // Diversify the seed into a state/stream pair, then run the standard
+        // PCG seeding sequence.
+        let mut s = seed;
+        let init_state = ((splitmix64(&mut s) as u128) << 64) | splitmix64(&mut s) as u128;
+        let init_seq = ((splitmix64(&mut s) as u128) << 64) | splitmix64(&mut s) as u128;
+        let mut rng = Self {
+            state: 0,
+            inc: (init_seq << 1) | 1,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(init_state);
+        rng.step();
+        rng
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(Self::MUL).wrapping_add(self.inc);
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.step();
+        let rot = (self.state >> 122) as u32;
+        let xored = ((self.state >> 64) ^ self.state) as u64;
+        xored.rotate_right(rot)
+    }
+}
+
+impl RngSource for Pcg64 {
+    fn next_f64(&mut self) -> f64 {
+        // 53-bit mantissa for a uniform double.
+        (self.next_u64() >> 11) as f64 / ((1u64 << 53) as f64)
+    }
+}
+
+/// ChaCha8 — the ChaCha stream cipher reduced to 8 rounds, keyed by the seed.
+pub struct ChaCha8 {
+    key: [u32; 8],
+    counter: u64,
+    block: [u32; 16],
+    idx: usize,
+}
+
+impl ChaCha8 {
+    pub fn new(seed: u64) -> Self {
+        let mut s = seed;
+        let mut key = [0u32; 8];
+        for k in key.iter_mut() {
+            *k = splitmix64(&mut s) as u32;
+        }
+        Self {
+            key,
+            counter: 0,
+            block: [0u32; 16],
+            idx: 16, // force a refill on first draw
+        }
+    }
+
+    fn quarter_round(st: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        st[a] = st[a].wrapping_add(st[b]);
+        st[d] = (st[d] ^ st[a]).rotate_left(16);
+        st[c] = st[c].wrapping_add(st[d]);
+        st[b] = (st[b] ^ st[c]).rotate_left(12);
+        st[a] = st[a].wrapping_add(st[b]);
+        st[d] = (st[d] ^ st[a]).rotate_left(8);
+        st[c] = st[c].wrapping_add(st[d]);
+        st[b] = (st[b] ^ st[c]).rotate_left(7);
+    }
+
+    fn refill(&mut self) {
+        let mut st = [
+            0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574, // "expand 32-byte k"
+            self.key[0], self.key[1], self.key[2], self.key[3],
+            self.key[4], self.key[5], self.key[6], self.key[7],
+            self.counter as u32, (self.counter >> 32) as u32, 0, 0,
+        ];
+        let init = st;
+        // 8 rounds = 4 column + 4 diagonal double-rounds.
+        for _ in 0..4 {
+            Self::quarter_round(&mut st, 0, 4, 8, 12);
+            Self::quarter_round(&mut st, 1, 5, 9, 13);
+            Self::quarter_round(&mut st, 2, 6, 10, 14);
+            Self::quarter_round(&mut st, 3, 7, 11, 15);
+            Self::quarter_round(&mut st, 0, 5, 10, 15);
+            Self::quarter_round(&mut st, 1, 6, 11, 12);
+            Self::quarter_round(&mut st, 2, 7, 8, 13);
+            Self::quarter_round(&mut st, 3, 4, 9, 14);
+        }
+        for i in 0..16 {
+            self.block[i] = st[i].wrapping_add(init[i]);
+        }
+        self.counter = self.counter.wrapping_add(1);
+        self.idx = 0;
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.idx >= 16 {
+            self.refill();
+        }
+        let v = self.block[self.idx];
+        self.idx += 1;
+        v
+    }
+}
+
+impl RngSource for ChaCha8 {
+    fn next_f64(&mut self) -> f64 {
+        let hi = self.next_u32() as u64;
+        let lo = self.next_u32() as u64;
+        let u = (hi << 32) | lo;
+        (u >> 11) as f64 / ((1u64 << 53) as f64)
+    }
+}
+
+/// Arbitrary penalty / regularization term on a design vector.
+///
+/// Registered on an [`NSGA2`] instance, a regularizer contributes a
+/// non-negative scalar that is folded into each individual's objectives or
+/// constraint violation according to [`NSGA2Config::reg_mode`]. It may also
+/// repair a design vector in place (box projection) so infeasible offspring
+/// are fixed rather than merely penalized.
+pub trait Regularizer {
+    /// Non-negative penalty / violation contribution for design vector `x`.
+    fn penalty(&self, x: &[f64]) -> f64;
+
+    /// Repair `x` in place. Defaults to a no-op; implementors that can
+    /// project onto their feasible set (e.g. non-negativity, integrality)
+    /// should override this.
+    fn project(&self, _x: &mut [f64]) {}
+}
+
+/// Which mutation operator the default NSGA-II wiring applies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MutationKind {
+    /// Polynomial mutation (historical default).
+    Polynomial,
+    /// Metropolis-style dual step: with probability `dual_p_large` a gene is
+    /// resampled uniformly within its bounds (global exploration), otherwise it
+    /// is perturbed by a bounded, log-uniform jitter reflected back into
+    /// `[lo, hi]` (local refinement). Favouring small steps late in a run
+    /// polishes the front while the occasional large step escapes local fronts.
+    Dual,
+}
+
+impl Default for MutationKind {
+    fn default() -> Self {
+        MutationKind::Polynomial
+    }
+}
+
+/// How regularizer contributions enter the selection criterion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegularizationMode {
+    /// Add contributions to the constraint violation `cv` (hard feasibility).
+    Hard,
+    /// Add contributions to every objective (soft penalty).
+    Soft,
+}
+
 /// Individual in the population
 #[derive(Clone, Debug)]
 pub struct Individual {
     /// Design variables: [bpr, opr, eta_comp, eta_turb]
     pub x: Vec<f64>,
-    /// Objective values: [tsfc, -thrust] (minimize both)
+    /// Objective values in minimization sense, one entry per configured
+    /// objective (a maximized metric enters negated).
     pub f: Vec<f64>,
     /// Constraint violations (sum of violations)
     pub cv: f64,
@@ -87,6 +339,31 @@ pub struct NSGA2Config {
     pub bounds: Vec<(f64, f64)>,
     /// Seed for reproducibility
     pub seed: u64,
+    /// How registered regularizers combine into the selection criterion
+    pub reg_mode: RegularizationMode,
+    /// Probability that a rank-0 individual is refined by local search each
+    /// generation (memetic hybridization). 0.0 disables local search.
+    pub local_search_prob: f64,
+    /// Initial pattern-search step as a fraction of each variable's range.
+    pub local_search_step: f64,
+    /// Maximum objective evaluations spent refining a single individual.
+    pub local_search_budget: usize,
+    /// Worker threads used to evaluate a generation concurrently (1 = serial).
+    pub threads: usize,
+    /// Pseudo-random generator backing the variation operators. Defaults to the
+    /// historical [`RngKind::Lcg`] for bit-for-bit reproducible runs.
+    pub rng_kind: RngKind,
+    /// Which mutation operator the default wiring applies.
+    pub mutation_kind: MutationKind,
+    /// Probability of a global "large step" gene resample under
+    /// [`MutationKind::Dual`].
+    pub dual_p_large: f64,
+    /// Smallest "small step" jitter as a fraction of each variable's range
+    /// under [`MutationKind::Dual`].
+    pub dual_step_min: f64,
+    /// Largest "small step" jitter as a fraction of each variable's range
+    /// under [`MutationKind::Dual`].
+    pub dual_step_max: f64,
 }
 
 impl Default for NSGA2Config {
@@ -105,6 +382,16 @@ impl Default for NSGA2Config {
                 (0.80, 0.92), // eta_turb
             ],
             seed: 42,
+            reg_mode: RegularizationMode::Hard,
+            local_search_prob: 0.0,
+            local_search_step: 0.1,
+            local_search_budget: 20,
+            threads: 1,
+            rng_kind: RngKind::Lcg,
+            mutation_kind: MutationKind::Polynomial,
+            dual_p_large: 0.1,
+            dual_step_min: 1e-3,
+            dual_step_max: 0.1,
         }
     }
 }
@@ -120,75 +407,246 @@ pub struct ParetoFront {
     pub hypervolume: Option<f64>,
 }
 
-/// NSGA-II optimizer
-pub struct NSGA2 {
+// ============================================================================
+// Pluggable genetic operators
+//
+// The four stages of a generation — initialization, parent selection, crossover
+// and mutation — are expressed as traits so an [`NSGA2`] can be parameterized
+// over them rather than hard-coding the LHS / tournament / SBX / polynomial
+// quartet. The default operators below reproduce the historical behaviour (and
+// delegate to the same free functions SPEA2 uses), so `NSGA2::new(config)`
+// continues to build the classic algorithm. Swapping in, say, a size-`k`
+// tournament or a Sobol initializer is then a matter of implementing the
+// relevant trait and calling [`NSGA2::with_operators`].
+// ============================================================================
+
+/// Produces the initial population within the configured bounds.
+pub trait Generate {
+    /// Build `config.pop_size` starting individuals.
+    fn initialize(&self, config: &NSGA2Config, rng: &mut dyn RngSource) -> Vec<Individual>;
+}
+
+/// Picks a parent index from a ranked, crowding-annotated population.
+pub trait Select {
+    /// Return the index of the chosen parent in `population`.
+    fn select(&self, population: &[Individual], rng: &mut dyn RngSource) -> usize;
+}
+
+/// Recombines two parent design vectors into two children.
+pub trait Crossover {
+    /// Produce two children from parents `p1` and `p2`.
+    fn crossover(
+        &self,
+        config: &NSGA2Config,
+        rng: &mut dyn RngSource,
+        p1: &[f64],
+        p2: &[f64],
+    ) -> (Vec<f64>, Vec<f64>);
+}
+
+/// Perturbs a design vector in place.
+pub trait Mutation {
+    /// Mutate `x` within the configured bounds.
+    fn mutate(&self, config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]);
+}
+
+/// Latin Hypercube Sampling — the historical initializer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LatinHypercube;
+
+impl Generate for LatinHypercube {
+    fn initialize(&self, config: &NSGA2Config, rng: &mut dyn RngSource) -> Vec<Individual> {
+        latin_hypercube_population(config, rng)
+    }
+}
+
+/// Binary tournament on rank, ties broken by crowding distance.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrowdedTournament;
+
+impl Select for CrowdedTournament {
+    fn select(&self, population: &[Individual], rng: &mut dyn RngSource) -> usize {
+        let a = rng.gen_range(population.len());
+        let b = rng.gen_range(population.len());
+        let ind_a = &population[a];
+        let ind_b = &population[b];
+        if ind_a.rank < ind_b.rank {
+            a
+        } else if ind_b.rank < ind_a.rank {
+            b
+        } else if ind_a.crowding_distance > ind_b.crowding_distance {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+/// Simulated Binary Crossover — the historical recombination operator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SbxCrossover;
+
+impl Crossover for SbxCrossover {
+    fn crossover(
+        &self,
+        config: &NSGA2Config,
+        rng: &mut dyn RngSource,
+        p1: &[f64],
+        p2: &[f64],
+    ) -> (Vec<f64>, Vec<f64>) {
+        sbx_crossover(config, rng, p1, p2)
+    }
+}
+
+/// Polynomial mutation — the historical mutation operator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PolynomialMutation;
+
+impl Mutation for PolynomialMutation {
+    fn mutate(&self, config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+        polynomial_mutation(config, rng, x);
+    }
+}
+
+/// Metropolis-style dual-step mutation — see [`MutationKind::Dual`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DualMutation;
+
+impl Mutation for DualMutation {
+    fn mutate(&self, config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+        dual_mutation(config, rng, x);
+    }
+}
+
+/// Default mutation operator: dispatches on [`NSGA2Config::mutation_kind`] so
+/// the generator can be switched without changing the optimizer's type.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConfiguredMutation;
+
+impl Mutation for ConfiguredMutation {
+    fn mutate(&self, config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+        apply_mutation(config, rng, x);
+    }
+}
+
+/// NSGA-II optimizer, generic over its genetic operators.
+pub struct NSGA2<
+    G = LatinHypercube,
+    S = CrowdedTournament,
+    C = SbxCrossover,
+    M = ConfiguredMutation,
+> {
     config: NSGA2Config,
     population: Vec<Individual>,
-    rng_state: u64,
+    rng: Box<dyn RngSource>,
+    regularizers: Vec<Box<dyn Regularizer>>,
+    /// Extra objective evaluations spent on memetic local search.
+    local_search_evals: usize,
+    generate: G,
+    select: S,
+    crossover: C,
+    mutation: M,
 }
 
 impl NSGA2 {
+    /// Build an NSGA-II run with the classic LHS / tournament / SBX /
+    /// polynomial operators.
     pub fn new(config: NSGA2Config) -> Self {
+        NSGA2::with_operators(
+            config,
+            LatinHypercube,
+            CrowdedTournament,
+            SbxCrossover,
+            ConfiguredMutation,
+        )
+    }
+}
+
+impl<G, S, C, M> NSGA2<G, S, C, M>
+where
+    G: Generate,
+    S: Select,
+    C: Crossover,
+    M: Mutation,
+{
+    /// Build an NSGA-II run with explicit operators. Callers can mix and match
+    /// any initializer, selector, crossover and mutation implementation.
+    pub fn with_operators(config: NSGA2Config, generate: G, select: S, crossover: C, mutation: M) -> Self {
+        let rng = make_rng(config.rng_kind, config.seed);
         Self {
-            rng_state: config.seed,
+            rng,
             config,
             population: Vec::new(),
+            regularizers: Vec::new(),
+            local_search_evals: 0,
+            generate,
+            select,
+            crossover,
+            mutation,
         }
     }
 
-    /// Simple LCG random number generator (deterministic)
-    fn rand(&mut self) -> f64 {
-        // Linear congruential generator
-        self.rng_state = self.rng_state.wrapping_mul(6364136223846793005)
-            .wrapping_add(1442695040888963407);
-        ((self.rng_state >> 33) as f64) / ((1u64 << 31) as f64)
+    /// Register a penalty / regularization term on the design vector.
+    pub fn add_regularizer(&mut self, regularizer: Box<dyn Regularizer>) {
+        self.regularizers.push(regularizer);
     }
 
-    /// Generate random integer in [0, n)
-    fn rand_int(&mut self, n: usize) -> usize {
-        (self.rand() * n as f64) as usize
+    /// Repair a design vector through every registered regularizer.
+    fn repair(&self, x: &mut [f64]) {
+        for r in &self.regularizers {
+            r.project(x);
+        }
     }
 
-    /// Initialize population with Latin Hypercube Sampling
-    pub fn initialize_population(&mut self) {
-        let n = self.config.pop_size;
-        let d = self.config.bounds.len();
-
-        // Simple LHS: divide each dimension into n intervals
-        let mut indices: Vec<Vec<usize>> = (0..d)
-            .map(|_| (0..n).collect())
-            .collect();
-
-        // Shuffle each dimension
-        for dim in indices.iter_mut() {
-            for i in (1..n).rev() {
-                let j = self.rand_int(i + 1);
-                dim.swap(i, j);
+    /// Fold regularizer contributions into `(f, cv)` per the configured mode.
+    fn combine(&self, x: &[f64], mut f: Vec<f64>, mut cv: f64) -> (Vec<f64>, f64) {
+        let penalty: f64 = self.regularizers.iter().map(|r| r.penalty(x)).sum();
+        match self.config.reg_mode {
+            RegularizationMode::Hard => cv += penalty,
+            RegularizationMode::Soft => {
+                for fi in f.iter_mut() {
+                    *fi += penalty;
+                }
             }
         }
+        (f, cv)
+    }
 
-        // Create individuals
-        self.population = Vec::with_capacity(n);
-        for i in 0..n {
-            let x: Vec<f64> = (0..d)
-                .map(|j| {
-                    let (lo, hi) = self.config.bounds[j];
-                    let idx = indices[j][i] as f64;
-                    let u = (idx + self.rand()) / n as f64;
-                    lo + u * (hi - lo)
-                })
-                .collect();
-            self.population.push(Individual::new(x));
-        }
+    /// Draw from the configured random source (deterministic per seed).
+    fn rand(&mut self) -> f64 {
+        self.rng.next_f64()
+    }
+
+    /// Initialize population through the configured [`Generate`] operator.
+    pub fn initialize_population(&mut self) {
+        self.population = self.generate.initialize(&self.config, self.rng.as_mut());
     }
 
     /// Evaluate population using provided objective function
     pub fn evaluate<F>(&mut self, eval_fn: &F)
     where
-        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>),
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
     {
-        for ind in self.population.iter_mut() {
-            let (f, cv, status, outputs) = eval_fn(&ind.x);
+        // Repair every decision vector first (uses only immutable state), then
+        // evaluate the whole population concurrently. The raw objective results
+        // come back in population order, so the outcome is independent of the
+        // thread count.
+        let xs: Vec<Vec<f64>> = self
+            .population
+            .iter()
+            .map(|ind| {
+                let mut x = ind.x.clone();
+                self.repair(&mut x);
+                x
+            })
+            .collect();
+
+        let raw = parallel::par_map(&xs, self.config.threads, |_, x| eval_fn(x));
+
+        for (i, (x, (f, cv, status, outputs))) in xs.into_iter().zip(raw).enumerate() {
+            let (f, cv) = self.combine(&x, f, cv);
+            let ind = &mut self.population[i];
+            ind.x = x;
             ind.f = f;
             ind.cv = cv;
             ind.status = status;
@@ -316,107 +774,33 @@ impl NSGA2 {
         }
     }
 
-    /// Tournament selection
+    /// Parent selection through the configured [`Select`] operator.
     fn tournament_select(&mut self) -> usize {
-        let a = self.rand_int(self.population.len());
-        let b = self.rand_int(self.population.len());
-
-        // Compare by rank first, then crowding distance
-        let ind_a = &self.population[a];
-        let ind_b = &self.population[b];
-
-        if ind_a.rank < ind_b.rank {
-            a
-        } else if ind_b.rank < ind_a.rank {
-            b
-        } else if ind_a.crowding_distance > ind_b.crowding_distance {
-            a
-        } else {
-            b
-        }
+        self.select.select(&self.population, self.rng.as_mut())
     }
 
-    /// Simulated Binary Crossover (SBX)
+    /// Recombination through the configured [`Crossover`] operator.
     fn sbx_crossover(&mut self, p1: &[f64], p2: &[f64]) -> (Vec<f64>, Vec<f64>) {
-        let d = p1.len();
-        let mut c1 = p1.to_vec();
-        let mut c2 = p2.to_vec();
-
-        if self.rand() > self.config.crossover_prob {
-            return (c1, c2);
-        }
-
-        for i in 0..d {
-            if self.rand() > 0.5 {
-                continue;
-            }
-
-            let (lo, hi) = self.config.bounds[i];
-            let y1 = p1[i].min(p2[i]);
-            let y2 = p1[i].max(p2[i]);
-
-            if (y2 - y1).abs() < 1e-10 {
-                continue;
-            }
-
-            let beta = 1.0 + (2.0 * (y1 - lo) / (y2 - y1));
-            let alpha = 2.0 - beta.powf(-(self.config.eta_c + 1.0));
-            let u = self.rand();
-            let betaq = if u <= 1.0 / alpha {
-                (u * alpha).powf(1.0 / (self.config.eta_c + 1.0))
-            } else {
-                (1.0 / (2.0 - u * alpha)).powf(1.0 / (self.config.eta_c + 1.0))
-            };
-
-            c1[i] = 0.5 * ((y1 + y2) - betaq * (y2 - y1));
-            c2[i] = 0.5 * ((y1 + y2) + betaq * (y2 - y1));
-
-            // Bound enforcement
-            c1[i] = c1[i].max(lo).min(hi);
-            c2[i] = c2[i].max(lo).min(hi);
-        }
-
-        (c1, c2)
+        self.crossover.crossover(&self.config, self.rng.as_mut(), p1, p2)
     }
 
-    /// Polynomial mutation
+    /// Mutation through the configured [`Mutation`] operator.
     fn polynomial_mutation(&mut self, x: &mut [f64]) {
-        let d = x.len();
-        for i in 0..d {
-            if self.rand() > self.config.mutation_prob {
-                continue;
-            }
-
-            let (lo, hi) = self.config.bounds[i];
-            let y = x[i];
-            let delta1 = (y - lo) / (hi - lo);
-            let delta2 = (hi - y) / (hi - lo);
-
-            let u = self.rand();
-            let deltaq = if u < 0.5 {
-                let xy = 1.0 - delta1;
-                let val = 2.0 * u + (1.0 - 2.0 * u) * xy.powf(self.config.eta_m + 1.0);
-                val.powf(1.0 / (self.config.eta_m + 1.0)) - 1.0
-            } else {
-                let xy = 1.0 - delta2;
-                let val = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * xy.powf(self.config.eta_m + 1.0);
-                1.0 - val.powf(1.0 / (self.config.eta_m + 1.0))
-            };
-
-            x[i] = y + deltaq * (hi - lo);
-            x[i] = x[i].max(lo).min(hi);
-        }
+        self.mutation.mutate(&self.config, self.rng.as_mut(), x);
     }
 
     /// Create offspring population
     pub fn create_offspring<F>(&mut self, eval_fn: &F) -> Vec<Individual>
     where
-        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>),
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
     {
         let n = self.config.pop_size;
-        let mut offspring = Vec::with_capacity(n);
 
-        while offspring.len() < n {
+        // Phase 1: build the offspring decision vectors. Selection, crossover
+        // and mutation all draw from the (serial) RNG, so this stays sequential
+        // to preserve reproducibility.
+        let mut xs: Vec<Vec<f64>> = Vec::with_capacity(n);
+        while xs.len() < n {
             let p1_idx = self.tournament_select();
             let p2_idx = self.tournament_select();
             let p1 = &self.population[p1_idx].x;
@@ -425,27 +809,115 @@ impl NSGA2 {
             let (mut c1, mut c2) = self.sbx_crossover(p1, p2);
             self.polynomial_mutation(&mut c1);
             self.polynomial_mutation(&mut c2);
+            self.repair(&mut c1);
+            self.repair(&mut c2);
+
+            xs.push(c1);
+            if xs.len() < n {
+                xs.push(c2);
+            }
+        }
 
-            let mut ind1 = Individual::new(c1);
-            let (f, cv, status, outputs) = eval_fn(&ind1.x);
-            ind1.f = f;
-            ind1.cv = cv;
-            ind1.status = status;
-            ind1.outputs = outputs;
-            offspring.push(ind1);
+        // Phase 2: evaluate the batch concurrently, then fold in the
+        // regularizers in index order.
+        let raw = parallel::par_map(&xs, self.config.threads, |_, x| eval_fn(x));
+
+        xs.into_iter()
+            .zip(raw)
+            .map(|(x, (f, cv, status, outputs))| {
+                let (f, cv) = self.combine(&x, f, cv);
+                let mut ind = Individual::new(x);
+                ind.f = f;
+                ind.cv = cv;
+                ind.status = status;
+                ind.outputs = outputs;
+                ind
+            })
+            .collect()
+    }
 
-            if offspring.len() < n {
-                let mut ind2 = Individual::new(c2);
-                let (f, cv, status, outputs) = eval_fn(&ind2.x);
-                ind2.f = f;
-                ind2.cv = cv;
-                ind2.status = status;
-                ind2.outputs = outputs;
-                offspring.push(ind2);
+    /// Evaluate a single design vector into a fully-formed individual, applying
+    /// repair and the registered regularizers just like [`evaluate`].
+    fn eval_individual<F>(&self, x: Vec<f64>, eval_fn: &F) -> Individual
+    where
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>),
+    {
+        let mut x = x;
+        self.repair(&mut x);
+        let (f, cv, status, outputs) = eval_fn(&x);
+        let (f, cv) = self.combine(&x, f, cv);
+        let mut ind = Individual::new(x);
+        ind.f = f;
+        ind.cv = cv;
+        ind.status = status;
+        ind.outputs = outputs;
+        ind
+    }
+
+    /// Memetic local search: refine the current rank-0 individuals with a short
+    /// bounded pattern search on the decision variables.
+    ///
+    /// Each selected individual is perturbed one coordinate at a time by
+    /// `±step·range` within bounds; a move is accepted only if it converges
+    /// (`status == 0`) and Pareto-dominates the incumbent. When a full sweep
+    /// finds no improving move the step is halved, and the search stops once the
+    /// per-individual evaluation budget is spent. Refined individuals replace
+    /// their originals in place so they survive into the next selection.
+    pub fn local_search<F>(&mut self, eval_fn: &F)
+    where
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>),
+    {
+        if self.config.local_search_prob <= 0.0 || self.config.local_search_budget == 0 {
+            return;
+        }
+
+        let d = self.config.bounds.len();
+        let rank0: Vec<usize> = (0..self.population.len())
+            .filter(|&i| self.population[i].rank == 0)
+            .collect();
+
+        for idx in rank0 {
+            if self.rand() > self.config.local_search_prob {
+                continue;
+            }
+
+            let mut incumbent = self.population[idx].clone();
+            let mut step = self.config.local_search_step;
+            let mut evals = 0usize;
+
+            while evals < self.config.local_search_budget && step > 1e-4 {
+                let mut improved = false;
+                'coords: for j in 0..d {
+                    let (lo, hi) = self.config.bounds[j];
+                    let delta = step * (hi - lo);
+                    for &sign in &[1.0_f64, -1.0] {
+                        if evals >= self.config.local_search_budget {
+                            break 'coords;
+                        }
+                        let mut x = incumbent.x.clone();
+                        x[j] = (x[j] + sign * delta).max(lo).min(hi);
+                        let candidate = self.eval_individual(x, eval_fn);
+                        evals += 1;
+                        self.local_search_evals += 1;
+                        if candidate.status == 0 && candidate.dominates(&incumbent) {
+                            incumbent = candidate;
+                            improved = true;
+                            break 'coords;
+                        }
+                    }
+                }
+                if !improved {
+                    step *= 0.5;
+                }
             }
+
+            self.population[idx] = incumbent;
         }
+    }
 
-        offspring
+    /// Total extra objective evaluations spent on memetic local search.
+    pub fn local_search_evals(&self) -> usize {
+        self.local_search_evals
     }
 
     /// Environmental selection (truncate to pop_size)
@@ -482,10 +954,41 @@ impl NSGA2 {
         self.population = selected;
     }
 
-    /// Run optimization
+    /// Snapshot the current rank-0 front with its hypervolume filled against an
+    /// auto-derived reference point.
+    fn current_front(&self, generation: usize) -> ParetoFront {
+        let front: Vec<Individual> = self.population
+            .iter()
+            .filter(|ind| ind.rank == 0)
+            .cloned()
+            .collect();
+        let ref_point = auto_reference_point(&front);
+        let hv = hypervolume(&front, &ref_point);
+        ParetoFront {
+            solutions: front,
+            generation,
+            hypervolume: Some(hv),
+        }
+    }
+
+    /// Run optimization.
     pub fn optimize<F>(&mut self, eval_fn: F) -> ParetoFront
     where
-        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>),
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
+    {
+        self.optimize_with(eval_fn, |_, _, _| {})
+    }
+
+    /// Run optimization, invoking `on_generation(gen, front, population)` after
+    /// each generation's environmental selection (and any memetic refinement).
+    ///
+    /// The snapshot front carries the per-generation hypervolume, so callers can
+    /// log a convergence trajectory (e.g. through
+    /// [`CsvWriter`](crate::io::CsvWriter)) without re-running the algorithm.
+    pub fn optimize_with<F, H>(&mut self, eval_fn: F, mut on_generation: H) -> ParetoFront
+    where
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
+        H: FnMut(usize, &ParetoFront, &[Individual]),
     {
         // Initialize
         self.initialize_population();
@@ -494,23 +997,17 @@ impl NSGA2 {
         self.crowding_distance();
 
         // Main loop
-        for _gen in 0..self.config.generations {
+        for gen in 0..self.config.generations {
             let offspring = self.create_offspring(&eval_fn);
             self.environmental_selection(offspring);
-        }
-
-        // Extract Pareto front (rank 0)
-        let front: Vec<Individual> = self.population
-            .iter()
-            .filter(|ind| ind.rank == 0)
-            .cloned()
-            .collect();
+            // Memetic refinement of the rank-0 front (no-op unless enabled).
+            self.local_search(&eval_fn);
 
-        ParetoFront {
-            solutions: front,
-            generation: self.config.generations,
-            hypervolume: None,
+            let front = self.current_front(gen);
+            on_generation(gen, &front, &self.population);
         }
+
+        self.current_front(self.config.generations)
     }
 
     /// Get current population
@@ -519,38 +1016,545 @@ impl NSGA2 {
     }
 }
 
-/// Compute hypervolume indicator (2D only for simplicity)
-pub fn hypervolume_2d(front: &[Individual], ref_point: (f64, f64)) -> f64 {
-    if front.is_empty() {
-        return 0.0;
+// ============================================================================
+// Shared operators
+//
+// The variation operators and the LHS initializer are expressed as free
+// functions over a `(config, rng)` pair so both [`NSGA2`] and [`Spea2`] draw
+// from the same [`RngSource`] and the same SBX / polynomial mutation maths.
+// ============================================================================
+
+/// Build an initial population by Latin Hypercube Sampling within the bounds.
+fn latin_hypercube_population(config: &NSGA2Config, rng: &mut dyn RngSource) -> Vec<Individual> {
+    let n = config.pop_size;
+    let d = config.bounds.len();
+
+    // Simple LHS: divide each dimension into n intervals
+    let mut indices: Vec<Vec<usize>> = (0..d).map(|_| (0..n).collect()).collect();
+
+    // Shuffle each dimension
+    for dim in indices.iter_mut() {
+        for i in (1..n).rev() {
+            let j = rng.gen_range(i + 1);
+            dim.swap(i, j);
+        }
     }
 
-    // Sort by first objective
-    let mut sorted: Vec<&Individual> = front.iter().collect();
-    sorted.sort_by(|a, b| {
-        a.f[0].partial_cmp(&b.f[0]).unwrap_or(Ordering::Equal)
-    });
+    // Create individuals
+    let mut population = Vec::with_capacity(n);
+    for i in 0..n {
+        let x: Vec<f64> = (0..d)
+            .map(|j| {
+                let (lo, hi) = config.bounds[j];
+                let idx = indices[j][i] as f64;
+                let u = (idx + rng.next_f64()) / n as f64;
+                lo + u * (hi - lo)
+            })
+            .collect();
+        population.push(Individual::new(x));
+    }
+    population
+}
 
-    let mut hv = 0.0;
-    let mut prev_f2 = ref_point.1;
-
-    for ind in sorted {
-        if ind.f[0] < ref_point.0 && ind.f[1] < ref_point.1 {
-            let width = ref_point.0 - ind.f[0];
-            let height = prev_f2 - ind.f[1];
-            if height > 0.0 {
-                hv += width * height;
-            }
-            prev_f2 = ind.f[1];
+/// Simulated Binary Crossover (SBX).
+fn sbx_crossover(config: &NSGA2Config, rng: &mut dyn RngSource, p1: &[f64], p2: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let d = p1.len();
+    let mut c1 = p1.to_vec();
+    let mut c2 = p2.to_vec();
+
+    if rng.next_f64() > config.crossover_prob {
+        return (c1, c2);
+    }
+
+    for i in 0..d {
+        if rng.next_f64() > 0.5 {
+            continue;
+        }
+
+        let (lo, hi) = config.bounds[i];
+        let y1 = p1[i].min(p2[i]);
+        let y2 = p1[i].max(p2[i]);
+
+        if (y2 - y1).abs() < 1e-10 {
+            continue;
         }
+
+        let beta = 1.0 + (2.0 * (y1 - lo) / (y2 - y1));
+        let alpha = 2.0 - beta.powf(-(config.eta_c + 1.0));
+        let u = rng.next_f64();
+        let betaq = if u <= 1.0 / alpha {
+            (u * alpha).powf(1.0 / (config.eta_c + 1.0))
+        } else {
+            (1.0 / (2.0 - u * alpha)).powf(1.0 / (config.eta_c + 1.0))
+        };
+
+        c1[i] = 0.5 * ((y1 + y2) - betaq * (y2 - y1));
+        c2[i] = 0.5 * ((y1 + y2) + betaq * (y2 - y1));
+
+        // Bound enforcement
+        c1[i] = c1[i].max(lo).min(hi);
+        c2[i] = c2[i].max(lo).min(hi);
     }
 
-    hv
+    (c1, c2)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Polynomial mutation.
+fn polynomial_mutation(config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+    let d = x.len();
+    for i in 0..d {
+        if rng.next_f64() > config.mutation_prob {
+            continue;
+        }
+
+        let (lo, hi) = config.bounds[i];
+        let y = x[i];
+        let delta1 = (y - lo) / (hi - lo);
+        let delta2 = (hi - y) / (hi - lo);
+
+        let u = rng.next_f64();
+        let deltaq = if u < 0.5 {
+            let xy = 1.0 - delta1;
+            let val = 2.0 * u + (1.0 - 2.0 * u) * xy.powf(config.eta_m + 1.0);
+            val.powf(1.0 / (config.eta_m + 1.0)) - 1.0
+        } else {
+            let xy = 1.0 - delta2;
+            let val = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * xy.powf(config.eta_m + 1.0);
+            1.0 - val.powf(1.0 / (config.eta_m + 1.0))
+        };
+
+        x[i] = y + deltaq * (hi - lo);
+        x[i] = x[i].max(lo).min(hi);
+    }
+}
+
+/// Apply the mutation selected by `config.mutation_kind`. Shared by the default
+/// [`ConfiguredMutation`] operator and [`Spea2`] so both honour the setting.
+fn apply_mutation(config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+    match config.mutation_kind {
+        MutationKind::Polynomial => polynomial_mutation(config, rng, x),
+        MutationKind::Dual => dual_mutation(config, rng, x),
+    }
+}
+
+/// Metropolis-style dual-step mutation (see [`MutationKind::Dual`]).
+///
+/// Each gene mutates with probability `mutation_prob`. A mutating gene takes a
+/// "large step" — a uniform resample inside its bounds — with probability
+/// `dual_p_large`; otherwise it takes a "small step" whose magnitude is a
+/// log-uniform fraction of the range drawn from `[dual_step_min, dual_step_max]`,
+/// signed at random and reflected back into `[lo, hi]`.
+fn dual_mutation(config: &NSGA2Config, rng: &mut dyn RngSource, x: &mut [f64]) {
+    let d = x.len();
+    for i in 0..d {
+        if rng.next_f64() > config.mutation_prob {
+            continue;
+        }
+
+        let (lo, hi) = config.bounds[i];
+        let range = hi - lo;
+        if range <= 0.0 {
+            continue;
+        }
+
+        if rng.next_f64() < config.dual_p_large {
+            // Large step: global resample within the bounds.
+            x[i] = lo + rng.next_f64() * range;
+            continue;
+        }
+
+        // Small step: log-uniform scale in [dual_step_min, dual_step_max] so the
+        // window spans several orders of magnitude, signed and reflected.
+        let (smin, smax) = (config.dual_step_min, config.dual_step_max);
+        let u = rng.next_f64();
+        let scale = smax * (smin / smax).powf(u);
+        let sign = if rng.next_f64() < 0.5 { -1.0 } else { 1.0 };
+        x[i] = reflect_into(x[i] + sign * range * scale, lo, hi);
+    }
+}
+
+/// Reflect `v` back into `[lo, hi]` by folding it off the bounds, so a small
+/// step near an edge stays local instead of clamping onto the boundary.
+fn reflect_into(mut v: f64, lo: f64, hi: f64) -> f64 {
+    let range = hi - lo;
+    if range <= 0.0 {
+        return lo;
+    }
+    while v < lo || v > hi {
+        if v < lo {
+            v = lo + (lo - v);
+        } else if v > hi {
+            v = hi - (v - hi);
+        }
+    }
+    v
+}
+
+/// Euclidean distance between two individuals in objective space.
+fn objective_distance(a: &Individual, b: &Individual) -> f64 {
+    a.f.iter()
+        .zip(b.f.iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// ============================================================================
+// SPEA2
+// ============================================================================
+
+/// Strength-Pareto Evolutionary Algorithm 2 (SPEA2).
+///
+/// A density-estimation-based alternative to [`NSGA2`] that shares the same
+/// [`Individual`], [`NSGA2Config`] bounds and variation operators, but replaces
+/// rank/crowding with strength-Pareto fitness and a fixed-size archive. It
+/// often spreads the front more evenly on the TSFC/thrust trade.
+///
+/// Reference:
+/// - Zitzler, E., Laumanns, M., Thiele, L. "SPEA2: Improving the Strength
+///   Pareto Evolutionary Algorithm" (2001)
+pub struct Spea2 {
+    config: NSGA2Config,
+    /// Capacity of the external archive.
+    archive_size: usize,
+    rng: Box<dyn RngSource>,
+    population: Vec<Individual>,
+    archive: Vec<Individual>,
+}
+
+impl Spea2 {
+    /// Create a SPEA2 optimizer. The archive capacity defaults to the
+    /// population size, the usual SPEA2 convention.
+    pub fn new(config: NSGA2Config) -> Self {
+        let archive_size = config.pop_size;
+        let rng = make_rng(config.rng_kind, config.seed);
+        Self {
+            rng,
+            archive_size,
+            config,
+            population: Vec::new(),
+            archive: Vec::new(),
+        }
+    }
+
+    /// Create a SPEA2 optimizer with an explicit archive capacity.
+    pub fn with_archive_size(config: NSGA2Config, archive_size: usize) -> Self {
+        let rng = make_rng(config.rng_kind, config.seed);
+        Self {
+            rng,
+            archive_size,
+            config,
+            population: Vec::new(),
+            archive: Vec::new(),
+        }
+    }
+
+    /// Evaluate a batch of decision vectors into individuals.
+    fn eval_batch<F>(&self, pop: &mut [Individual], eval_fn: &F)
+    where
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
+    {
+        let xs: Vec<Vec<f64>> = pop.iter().map(|ind| ind.x.clone()).collect();
+        let raw = parallel::par_map(&xs, self.config.threads, |_, x| eval_fn(x));
+        for (ind, (f, cv, status, outputs)) in pop.iter_mut().zip(raw) {
+            ind.f = f;
+            ind.cv = cv;
+            ind.status = status;
+            ind.outputs = outputs;
+        }
+    }
+
+    /// Assign SPEA2 fitness `F(i) = R(i) + D(i)` to every member of `items`.
+    ///
+    /// `S(i)` is the number of individuals `i` dominates, `R(i)` the sum of the
+    /// strengths of `i`'s dominators, and `D(i) = 1/(sigma_k + 2)` the density
+    /// at the `k`-th nearest neighbour in objective space with
+    /// `k = floor(sqrt(M))`.
+    fn assign_fitness(items: &[Individual]) -> Vec<f64> {
+        let m = items.len();
+        if m == 0 {
+            return Vec::new();
+        }
+
+        let mut strength = vec![0usize; m];
+        for i in 0..m {
+            for j in 0..m {
+                if i != j && items[i].dominates(&items[j]) {
+                    strength[i] += 1;
+                }
+            }
+        }
+
+        let mut fitness = vec![0.0f64; m];
+        for i in 0..m {
+            let mut raw = 0.0;
+            for j in 0..m {
+                if i != j && items[j].dominates(&items[i]) {
+                    raw += strength[j] as f64;
+                }
+            }
+            fitness[i] = raw;
+        }
+
+        // Density term from the k-th nearest neighbour.
+        let k = (m as f64).sqrt().floor() as usize;
+        for i in 0..m {
+            let mut dists: Vec<f64> = (0..m)
+                .filter(|&j| j != i)
+                .map(|j| objective_distance(&items[i], &items[j]))
+                .collect();
+            dists.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            let sigma = if k >= 1 {
+                dists.get(k - 1).copied().unwrap_or_else(|| dists.last().copied().unwrap_or(0.0))
+            } else {
+                dists.first().copied().unwrap_or(0.0)
+            };
+            fitness[i] += 1.0 / (sigma + 2.0);
+        }
+
+        fitness
+    }
+
+    /// Environmental selection: fill the next archive of capacity
+    /// `archive_size` from `items` ranked by their fitness.
+    fn environmental_selection(&self, items: Vec<Individual>, fitness: &[f64]) -> Vec<Individual> {
+        let cap = self.archive_size;
+
+        // All nondominated individuals (F < 1) are archive candidates.
+        let mut selected: Vec<usize> = (0..items.len()).filter(|&i| fitness[i] < 1.0).collect();
+
+        if selected.len() < cap {
+            // Too few: fill remaining slots with the best dominated individuals.
+            let mut dominated: Vec<usize> =
+                (0..items.len()).filter(|&i| fitness[i] >= 1.0).collect();
+            dominated.sort_by(|&a, &b| {
+                fitness[a].partial_cmp(&fitness[b]).unwrap_or(Ordering::Equal)
+            });
+            for i in dominated {
+                if selected.len() >= cap {
+                    break;
+                }
+                selected.push(i);
+            }
+            return selected.into_iter().map(|i| items[i].clone()).collect();
+        }
+
+        // Exactly enough, or too many: truncate by iteratively removing the
+        // individual whose nearest remaining neighbour is closest (ties broken
+        // by the next-nearest neighbour, and so on).
+        let mut archive: Vec<Individual> = selected.drain(..).map(|i| items[i].clone()).collect();
+        while archive.len() > cap {
+            let victim = Self::most_crowded(&archive);
+            archive.remove(victim);
+        }
+        archive
+    }
+
+    /// Index of the individual whose sorted neighbour-distance vector is
+    /// lexicographically smallest (closest nearest neighbour, then second, ...).
+    fn most_crowded(archive: &[Individual]) -> usize {
+        let n = archive.len();
+        let sorted_dists = |i: usize| -> Vec<f64> {
+            let mut d: Vec<f64> = (0..n)
+                .filter(|&j| j != i)
+                .map(|j| objective_distance(&archive[i], &archive[j]))
+                .collect();
+            d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            d
+        };
+
+        let mut victim = 0;
+        let mut best = sorted_dists(0);
+        for i in 1..n {
+            let cand = sorted_dists(i);
+            if lex_less(&cand, &best) {
+                best = cand;
+                victim = i;
+            }
+        }
+        victim
+    }
+
+    /// Binary tournament on fitness (lower is better).
+    fn tournament(&mut self, fitness: &[f64]) -> usize {
+        let a = self.rng.gen_range(fitness.len());
+        let b = self.rng.gen_range(fitness.len());
+        if fitness[a] <= fitness[b] {
+            a
+        } else {
+            b
+        }
+    }
+
+    /// Produce a mating offspring population of `pop_size` from the archive.
+    fn mate(&mut self, archive: &[Individual], fitness: &[f64]) -> Vec<Individual> {
+        let n = self.config.pop_size;
+        let mut offspring: Vec<Individual> = Vec::with_capacity(n);
+        while offspring.len() < n {
+            let p1 = archive[self.tournament(fitness)].x.clone();
+            let p2 = archive[self.tournament(fitness)].x.clone();
+            let (mut c1, mut c2) = sbx_crossover(&self.config, self.rng.as_mut(), &p1, &p2);
+            apply_mutation(&self.config, self.rng.as_mut(), &mut c1);
+            apply_mutation(&self.config, self.rng.as_mut(), &mut c2);
+            offspring.push(Individual::new(c1));
+            if offspring.len() < n {
+                offspring.push(Individual::new(c2));
+            }
+        }
+        offspring
+    }
+
+    /// Run the optimization, mirroring [`NSGA2::optimize`].
+    pub fn optimize<F>(&mut self, eval_fn: F) -> ParetoFront
+    where
+        F: Fn(&[f64]) -> (Vec<f64>, f64, i32, Vec<f64>) + Sync,
+    {
+        self.population = latin_hypercube_population(&self.config, self.rng.as_mut());
+        let mut pop = std::mem::take(&mut self.population);
+        self.eval_batch(&mut pop, &eval_fn);
+        self.population = pop;
+
+        for _gen in 0..self.config.generations {
+            // Fitness over the union of population and current archive.
+            let mut union = self.population.clone();
+            union.extend(self.archive.clone());
+            let fitness = Self::assign_fitness(&union);
+
+            // Next archive.
+            let archive = self.environmental_selection(union, &fitness);
+
+            // Mate from the new archive (tournaments need its own fitness).
+            let arch_fitness = Self::assign_fitness(&archive);
+            let mut offspring = self.mate(&archive, &arch_fitness);
+            self.eval_batch(&mut offspring, &eval_fn);
+
+            self.archive = archive;
+            self.population = offspring;
+        }
+
+        // Final front: the nondominated members of the archive.
+        let fitness = Self::assign_fitness(&self.archive);
+        let solutions: Vec<Individual> = self
+            .archive
+            .iter()
+            .zip(fitness.iter())
+            .filter(|(_, &f)| f < 1.0)
+            .map(|(ind, _)| {
+                let mut ind = ind.clone();
+                ind.rank = 0;
+                ind
+            })
+            .collect();
+
+        let ref_point = auto_reference_point(&solutions);
+        let hv = hypervolume(&solutions, &ref_point);
+
+        ParetoFront {
+            solutions,
+            generation: self.config.generations,
+            hypervolume: Some(hv),
+        }
+    }
+
+    /// Get the current external archive.
+    pub fn get_archive(&self) -> &[Individual] {
+        &self.archive
+    }
+}
+
+/// Lexicographic `<` on two equal-purpose distance vectors.
+fn lex_less(a: &[f64], b: &[f64]) -> bool {
+    for (x, y) in a.iter().zip(b.iter()) {
+        if x < y {
+            return true;
+        }
+        if x > y {
+            return false;
+        }
+    }
+    a.len() < b.len()
+}
+
+/// Dominated hypervolume of a minimization front relative to `ref_point`.
+///
+/// Dimension-agnostic: `ref_point` may have any length matching the objective
+/// vectors, and the front may carry two, three or more objectives. The measure
+/// is computed by recursive objective slicing (HSO): the volume is sliced along
+/// the last objective into slabs, and each slab's cross-section is the
+/// hypervolume of the remaining objectives over the points that reach into it.
+/// This reduces to the classic staircase area in the 2D case.
+pub fn hypervolume(front: &[Individual], ref_point: &[f64]) -> f64 {
+    let points: Vec<Vec<f64>> = front.iter().map(|ind| ind.f.clone()).collect();
+    hv_slice(&points, ref_point)
+}
+
+/// Reference point for the hypervolume, derived automatically as the
+/// per-objective worst value over `front` plus a small margin so the boundary
+/// points still enclose a non-zero box. Returns an empty vector for an empty
+/// front (the hypervolume is then zero).
+pub fn auto_reference_point(front: &[Individual]) -> Vec<f64> {
+    if front.is_empty() {
+        return Vec::new();
+    }
+    let n_obj = front[0].f.len();
+    (0..n_obj)
+        .map(|m| {
+            let mut best = f64::INFINITY;
+            let mut worst = f64::NEG_INFINITY;
+            for ind in front {
+                best = best.min(ind.f[m]);
+                worst = worst.max(ind.f[m]);
+            }
+            let span = (worst - best).abs();
+            let margin = if span > 0.0 { 0.05 * span } else { 0.05 * worst.abs().max(1e-9) };
+            worst + margin
+        })
+        .collect()
+}
+
+/// Recursive slicing kernel operating on raw objective vectors.
+fn hv_slice(points: &[Vec<f64>], ref_point: &[f64]) -> f64 {
+    // Only points that strictly beat the reference in every objective bound a
+    // non-empty box; the rest contribute nothing.
+    let live: Vec<&Vec<f64>> = points
+        .iter()
+        .filter(|p| p.iter().zip(ref_point).all(|(&a, &r)| a < r))
+        .collect();
+    if live.is_empty() {
+        return 0.0;
+    }
+
+    let d = ref_point.len();
+    if d == 1 {
+        let best = live.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+        return ref_point[0] - best;
+    }
+
+    // Slice along the last objective: sweep its distinct coordinates from the
+    // reference down to the best, accumulating slab depth × cross-section area.
+    let k = d - 1;
+    let mut coords: Vec<f64> = live.iter().map(|p| p[k]).collect();
+    coords.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    coords.dedup();
+
+    let mut hv = 0.0;
+    let mut upper = ref_point[k];
+    for &c in coords.iter().rev() {
+        let slab: Vec<Vec<f64>> = live
+            .iter()
+            .filter(|p| p[k] <= c)
+            .map(|p| p[..k].to_vec())
+            .collect();
+        hv += hv_slice(&slab, &ref_point[..k]) * (upper - c);
+        upper = c;
+    }
+
+    hv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_dominance() {
@@ -587,5 +1591,263 @@ mod tests {
 
         let front = optimizer.optimize(eval_fn);
         assert!(!front.solutions.is_empty());
+        // `optimize` must fill the hypervolume against an auto reference point.
+        let hv = front.hypervolume.expect("hypervolume should be populated");
+        assert!(hv > 0.0, "a spread front should enclose positive volume");
+    }
+
+    #[test]
+    fn test_optimize_with_reports_every_generation() {
+        // The generation hook must fire once per generation with a populated
+        // hypervolume, so a caller can log a convergence trajectory.
+        let config = NSGA2Config {
+            pop_size: 16,
+            generations: 7,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+        let mut optimizer = NSGA2::new(config);
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0] / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let mut seen = Vec::new();
+        let front = optimizer.optimize_with(eval_fn, |gen, f, _pop| {
+            assert!(f.hypervolume.is_some(), "per-generation hypervolume must be set");
+            seen.push(gen);
+        });
+
+        assert_eq!(seen, (0..7).collect::<Vec<_>>());
+        assert!(!front.solutions.is_empty());
+    }
+
+    #[test]
+    fn test_parallel_evaluation_matches_serial() {
+        // The parallel evaluation path must collect results by index, so a
+        // seeded run is bit-for-bit identical to the serial path regardless of
+        // the worker-thread count.
+        let base = NSGA2Config {
+            pop_size: 24,
+            generations: 6,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0] / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let serial = NSGA2::new(NSGA2Config { threads: 1, ..base.clone() }).optimize(eval_fn);
+        let parallel = NSGA2::new(NSGA2Config { threads: 4, ..base }).optimize(eval_fn);
+
+        assert_eq!(serial.solutions.len(), parallel.solutions.len());
+        for (a, b) in serial.solutions.iter().zip(parallel.solutions.iter()) {
+            assert_eq!(a.x, b.x, "parallel front must match the serial front");
+            assert_eq!(a.f, b.f);
+        }
+    }
+
+    #[test]
+    fn test_local_search_spends_budget() {
+        // With local search enabled the optimizer must spend extra evaluations
+        // and still return a non-empty front.
+        let config = NSGA2Config {
+            pop_size: 20,
+            generations: 5,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            local_search_prob: 1.0,
+            local_search_step: 0.1,
+            local_search_budget: 8,
+            ..Default::default()
+        };
+
+        let mut optimizer = NSGA2::new(config);
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0] / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let front = optimizer.optimize(eval_fn);
+        assert!(!front.solutions.is_empty());
+        assert!(optimizer.local_search_evals() > 0);
+    }
+
+    #[test]
+    fn test_spea2_simple() {
+        let config = NSGA2Config {
+            pop_size: 20,
+            generations: 5,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+
+        let mut optimizer = Spea2::new(config);
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0] / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let front = optimizer.optimize(eval_fn);
+        assert!(!front.solutions.is_empty());
+        // The archive must never exceed its capacity.
+        assert!(optimizer.get_archive().len() <= 20);
+    }
+
+    #[test]
+    fn test_spea2_archive_truncates_to_capacity() {
+        // A dense, fully nondominated front must be truncated to the archive
+        // capacity by the density-based removal step.
+        let config = NSGA2Config {
+            pop_size: 30,
+            generations: 3,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+        let mut optimizer = Spea2::with_archive_size(config, 10);
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            // Linear Pareto front f1 + f2 = 1: every point is nondominated.
+            (vec![x[0], 1.0 - x[0]], 0.0, 0, vec![])
+        };
+
+        let _ = optimizer.optimize(eval_fn);
+        assert!(optimizer.get_archive().len() <= 10);
+    }
+
+    #[test]
+    fn test_dual_mutation_stays_in_bounds() {
+        // A dual-step run must keep every gene inside its bounds (large steps
+        // resample within range, small steps reflect off the edges) and still
+        // return a non-empty front.
+        let config = NSGA2Config {
+            pop_size: 20,
+            generations: 6,
+            bounds: vec![(0.2, 1.5), (4.0, 16.0)],
+            mutation_kind: MutationKind::Dual,
+            mutation_prob: 0.5,
+            dual_p_large: 0.2,
+            ..Default::default()
+        };
+
+        let mut optimizer = NSGA2::new(config);
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            (vec![x[0], x[1]], 0.0, 0, vec![])
+        };
+
+        let front = optimizer.optimize(eval_fn);
+        assert!(!front.solutions.is_empty());
+        for ind in optimizer.get_population() {
+            assert!((0.2..=1.5).contains(&ind.x[0]), "gene 0 in bounds");
+            assert!((4.0..=16.0).contains(&ind.x[1]), "gene 1 in bounds");
+        }
+    }
+
+    #[test]
+    fn test_custom_operators_plug_in() {
+        // A user-supplied size-`k` tournament plugged in via `with_operators`
+        // must drive the run in place of the default binary tournament.
+        struct KTournament {
+            k: usize,
+        }
+        impl Select for KTournament {
+            fn select(&self, population: &[Individual], rng: &mut dyn RngSource) -> usize {
+                let mut best = rng.gen_range(population.len());
+                for _ in 1..self.k {
+                    let c = rng.gen_range(population.len());
+                    let (bi, ci) = (&population[best], &population[c]);
+                    let better = ci.rank < bi.rank
+                        || (ci.rank == bi.rank && ci.crowding_distance > bi.crowding_distance);
+                    if better {
+                        best = c;
+                    }
+                }
+                best
+            }
+        }
+
+        let config = NSGA2Config {
+            pop_size: 20,
+            generations: 5,
+            bounds: vec![(0.0, 1.0), (0.0, 1.0)],
+            ..Default::default()
+        };
+
+        let mut optimizer = NSGA2::with_operators(
+            config,
+            LatinHypercube,
+            KTournament { k: 4 },
+            SbxCrossover,
+            PolynomialMutation,
+        );
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0] / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let front = optimizer.optimize(eval_fn);
+        assert!(!front.solutions.is_empty());
+    }
+
+    #[test]
+    fn test_nonnegativity_regularizer() {
+        // Box bounds that admit negatives; a non-negativity regularizer must
+        // keep every design component >= 0 via box projection while the run
+        // still yields a rank-0 front.
+        struct NonNegativity;
+        impl Regularizer for NonNegativity {
+            fn penalty(&self, x: &[f64]) -> f64 {
+                x.iter().map(|&v| if v < 0.0 { -v } else { 0.0 }).sum()
+            }
+            fn project(&self, x: &mut [f64]) {
+                for v in x.iter_mut() {
+                    if *v < 0.0 {
+                        *v = 0.0;
+                    }
+                }
+            }
+        }
+
+        let config = NSGA2Config {
+            pop_size: 20,
+            generations: 5,
+            bounds: vec![(-1.0, 1.0), (-1.0, 1.0)],
+            reg_mode: RegularizationMode::Hard,
+            ..Default::default()
+        };
+
+        let mut optimizer = NSGA2::new(config);
+        optimizer.add_regularizer(Box::new(NonNegativity));
+
+        let eval_fn = |x: &[f64]| -> (Vec<f64>, f64, i32, Vec<f64>) {
+            let f1 = x[0];
+            let g = 1.0 + x[1];
+            let f2 = g * (1.0 - (x[0].max(0.0) / g).sqrt());
+            (vec![f1, f2], 0.0, 0, vec![])
+        };
+
+        let front = optimizer.optimize(eval_fn);
+        assert!(front.solutions.iter().any(|ind| ind.rank == 0));
+        for ind in optimizer.get_population() {
+            for &v in &ind.x {
+                assert!(v >= 0.0, "projection must keep every component non-negative");
+            }
+        }
     }
 }
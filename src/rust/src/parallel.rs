@@ -0,0 +1,85 @@
+//! Index-stable parallel map over independent work items (v2.26).
+//!
+//! Population and grid evaluation are embarrassingly parallel: every
+//! `ffi::solve` is a deterministic call with no shared mutable state, so a
+//! whole generation or a full BPR×OPR grid can be evaluated across cores. This
+//! helper spreads the items over a fixed pool of scoped threads and writes each
+//! result back into its original slot, so the returned buffer — and therefore
+//! any CSV/JSON derived from it — is bit-for-bit identical regardless of the
+//! thread count.
+//!
+//! It deliberately avoids an external runtime: `std::thread::scope` borrows the
+//! work items for the duration of the pool, which is all the batch evaluators
+//! need.
+//!
+//! Author: CSTNSystems
+//! Version: 2.26.0
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Evaluate `f(i, &items[i])` for every item, using up to `threads` worker
+/// threads, and return the results in item order.
+///
+/// With `threads <= 1` (or a single item) the work runs inline on the calling
+/// thread, so the common serial path carries no threading overhead.
+pub fn par_map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &T) -> R + Sync,
+{
+    let n = items.len();
+    let threads = threads.max(1).min(n.max(1));
+
+    if threads <= 1 || n <= 1 {
+        return items.iter().enumerate().map(|(i, t)| f(i, t)).collect();
+    }
+
+    // Shared work counter (atomic work-stealing) plus per-index result slots.
+    let next = AtomicUsize::new(0);
+    let slots: Vec<Mutex<Option<R>>> = (0..n).map(|_| Mutex::new(None)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= n {
+                    break;
+                }
+                let r = f(i, &items[i]);
+                *slots[i].lock().unwrap() = Some(r);
+            });
+        }
+    });
+
+    slots
+        .into_iter()
+        .map(|slot| slot.into_inner().unwrap().expect("every slot filled"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_is_stable_across_thread_counts() {
+        let items: Vec<usize> = (0..1000).collect();
+        let square = |_i: usize, &x: &usize| x * x;
+        let serial = par_map(&items, 1, square);
+        let parallel = par_map(&items, 8, square);
+        assert_eq!(serial, parallel);
+        assert_eq!(serial[7], 49);
+    }
+
+    #[test]
+    fn test_index_and_item_agree() {
+        let items: Vec<usize> = (10..30).collect();
+        let out = par_map(&items, 4, |i, &x| (i, x));
+        for (i, (idx, val)) in out.iter().enumerate() {
+            assert_eq!(*idx, i);
+            assert_eq!(*val, items[i]);
+        }
+    }
+}
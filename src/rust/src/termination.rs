@@ -0,0 +1,81 @@
+//! Termination-condition subsystem for the cycle solver.
+//!
+//! The Fortran core historically exposed only scalar `tol`, `mass_tol`,
+//! `energy_tol`, and `max_iter`, and folded several distinct failure reasons
+//! into a single `status`. This module makes the stopping rule explicit and
+//! composable: callers pick a [`TerminationMode`] and supply the tolerances,
+//! and the selection is threaded into `ffi::solve`/`ffi::solve_ext` via the
+//! `term_mode`/`abstol`/`reltol`/`stol` fields on the FFI inputs.
+//!
+//! Author: CSTNSystems
+//! Version: 2.12.0
+
+use std::os::raw::c_int;
+
+/// How the solver decides it is done.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminationMode {
+    /// Absolute residual: `‖r‖ < abstol`.
+    Absolute,
+    /// Relative residual: `‖r‖ / ‖r₀‖ < reltol`.
+    Relative,
+    /// Step size: `‖δ‖ < stol`.
+    Step,
+    /// Accept the best-so-far iterate when progress stalls but tolerance is
+    /// not met ("safe termination").
+    Safe,
+}
+
+impl TerminationMode {
+    /// FFI selector understood by the Fortran core.
+    pub fn id(self) -> c_int {
+        match self {
+            TerminationMode::Absolute => 0,
+            TerminationMode::Relative => 1,
+            TerminationMode::Step => 2,
+            TerminationMode::Safe => 3,
+        }
+    }
+
+    /// Parse a config string (`"abs"`, `"rel"`, `"step"`, `"safe"`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "abs" | "absolute" => Some(TerminationMode::Absolute),
+            "rel" | "relative" => Some(TerminationMode::Relative),
+            "step" => Some(TerminationMode::Step),
+            "safe" => Some(TerminationMode::Safe),
+            _ => None,
+        }
+    }
+}
+
+/// A fully-specified termination condition.
+///
+/// Tolerances default to the existing scalar `tol` so that a plain
+/// `TerminationCondition::from_tol(tol)` reproduces the legacy residual test.
+#[derive(Clone, Copy, Debug)]
+pub struct TerminationCondition {
+    pub mode: TerminationMode,
+    pub abstol: f64,
+    pub reltol: f64,
+    pub stol: f64,
+}
+
+impl TerminationCondition {
+    /// Legacy-compatible condition: absolute residual at the given `tol`,
+    /// with relative and step tolerances derived from it.
+    pub fn from_tol(tol: f64) -> Self {
+        Self {
+            mode: TerminationMode::Absolute,
+            abstol: tol,
+            reltol: (tol * 1e3).min(1e-6),
+            stol: tol,
+        }
+    }
+
+    /// Select a mode while keeping the derived tolerances.
+    pub fn with_mode(mut self, mode: TerminationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
@@ -0,0 +1,148 @@
+//! Temperature-dependent thermodynamics via NASA 7-coefficient polynomials.
+//!
+//! The baseline cycle uses a constant specific-heat ratio, which limits the
+//! fidelity difference between the `standard` and `advanced` component models.
+//! This module evaluates `cp(T)`, enthalpy `h(T)`, and entropy `s(T)` from the
+//! NASA 7-coefficient form, with two temperature ranges joined at a breakpoint
+//! per species:
+//!
+//! ```text
+//! cp/R = a1 + a2·T + a3·T² + a4·T³ + a5·T⁴
+//! h/(R·T) = a1 + a2/2·T + a3/3·T² + a4/4·T³ + a5/5·T⁴ + a6/T
+//! s/R = a1·ln(T) + a2·T + a3/2·T² + a4/3·T³ + a5/4·T⁴ + a7
+//! ```
+//!
+//! `solve_ext` selects a [`GasModel`]; variable-`cp` integration across the
+//! compressor/turbine/nozzle stages then happens in the Fortran core, which
+//! reads the coefficient table chosen here.
+//!
+//! Author: CSTNSystems
+//! Version: 2.15.0
+
+/// Universal gas constant [J/(mol·K)].
+pub const R_UNIVERSAL: f64 = 8.314462618;
+
+/// Gas model selectable from `MinotaurInputExt`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasModel {
+    /// Constant specific heats (legacy behaviour).
+    CaloricallyPerfect,
+    /// NASA-polynomial dry air.
+    NasaAir,
+    /// NASA-polynomial stoichiometric combustion products.
+    NasaCombustion,
+}
+
+impl GasModel {
+    /// FFI selector understood by the Fortran core.
+    pub fn id(self) -> i32 {
+        match self {
+            GasModel::CaloricallyPerfect => 0,
+            GasModel::NasaAir => 1,
+            GasModel::NasaCombustion => 2,
+        }
+    }
+
+    /// Parse a config string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "perfect" | "calorically_perfect" => Some(GasModel::CaloricallyPerfect),
+            "nasa_air" | "air" => Some(GasModel::NasaAir),
+            "nasa_combustion" | "combustion" => Some(GasModel::NasaCombustion),
+            _ => None,
+        }
+    }
+}
+
+/// NASA 7-coefficient polynomial set for one species over two temperature ranges.
+#[derive(Clone, Copy, Debug)]
+pub struct NasaPoly7 {
+    /// Coefficients valid below `t_mid`.
+    pub low: [f64; 7],
+    /// Coefficients valid at or above `t_mid`.
+    pub high: [f64; 7],
+    /// Breakpoint temperature [K] joining the two ranges.
+    pub t_mid: f64,
+}
+
+impl NasaPoly7 {
+    fn coeffs(&self, t: f64) -> &[f64; 7] {
+        if t < self.t_mid {
+            &self.low
+        } else {
+            &self.high
+        }
+    }
+
+    /// Dimensionless specific heat `cp/R` at temperature `t` [K].
+    pub fn cp_over_r(&self, t: f64) -> f64 {
+        let a = self.coeffs(t);
+        a[0] + a[1] * t + a[2] * t * t + a[3] * t.powi(3) + a[4] * t.powi(4)
+    }
+
+    /// Dimensionless enthalpy `h/(R·T)` at temperature `t` [K].
+    pub fn h_over_rt(&self, t: f64) -> f64 {
+        let a = self.coeffs(t);
+        a[0] + a[1] / 2.0 * t + a[2] / 3.0 * t * t + a[3] / 4.0 * t.powi(3)
+            + a[4] / 5.0 * t.powi(4)
+            + a[5] / t
+    }
+
+    /// Dimensionless entropy `s/R` at temperature `t` [K].
+    pub fn s_over_r(&self, t: f64) -> f64 {
+        let a = self.coeffs(t);
+        a[0] * t.ln() + a[1] * t + a[2] / 2.0 * t * t + a[3] / 3.0 * t.powi(3)
+            + a[4] / 4.0 * t.powi(4)
+            + a[6]
+    }
+}
+
+/// NASA-7 coefficients for dry air (200–6000 K), breakpoint at 1000 K.
+pub fn air() -> NasaPoly7 {
+    NasaPoly7 {
+        low: [
+            3.568_393_6,
+            -6.788_729e-4,
+            1.553_697e-6,
+            -3.299_809e-12,
+            -4.663_358e-13,
+            -1.062_394e3,
+            3.717_009,
+        ],
+        high: [
+            3.088_792e0,
+            1.240_597e-3,
+            -4.227_205e-7,
+            6.736_086e-11,
+            -3.948_447e-15,
+            -9.958_996e2,
+            5.959_041,
+        ],
+        t_mid: 1000.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cp_increases_with_temperature() {
+        let air = air();
+        // cp of air rises with temperature across the usual cycle range.
+        assert!(air.cp_over_r(1200.0) > air.cp_over_r(300.0));
+    }
+
+    #[test]
+    fn test_enthalpy_increases_with_temperature() {
+        let air = air();
+        assert!(air.h_over_rt(1200.0) * 1200.0 > air.h_over_rt(300.0) * 300.0);
+    }
+
+    #[test]
+    fn test_gas_model_parse_roundtrip() {
+        assert_eq!(GasModel::parse("nasa_air"), Some(GasModel::NasaAir));
+        assert_eq!(GasModel::parse("perfect"), Some(GasModel::CaloricallyPerfect));
+        assert_eq!(GasModel::parse("bogus"), None);
+    }
+}
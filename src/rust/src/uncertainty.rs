@@ -0,0 +1,503 @@
+//! Monte Carlo uncertainty propagation.
+//!
+//! Supports the `Uncertainty` command: given per-parameter input distributions
+//! declared in config, draw Latin-hypercube-stratified samples, push each
+//! through the solver, and summarize the output distributions via empirical
+//! CDFs. This complements the deterministic `Sensitivity`/`Jacobian` modes,
+//! which only report local gradients, by quantifying design-margin risk.
+//!
+//! Author: CSTNSystems
+//! Version: 2.19.0
+
+/// Deterministic linear congruential generator, matching the one used by the
+/// NSGA-II optimizer so Monte Carlo runs are reproducible from a seed.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Uniform draw in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.state >> 33) as f64) / ((1u64 << 31) as f64)
+    }
+
+    /// Fisher–Yates shuffle.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = (self.next_f64() * (i + 1) as f64) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
+
+/// Inverse standard-normal CDF via Acklam's rational approximation
+/// (relative error < 1.15e-9 over the open interval).
+pub fn norm_ppf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.383577518672690e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+    const P_HIGH: f64 = 1.0 - P_LOW;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= P_HIGH {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    }
+}
+
+/// Draw `n` Latin-hypercube samples over `k` parameters in [0, 1).
+///
+/// Each parameter's unit interval is split into `n` equal strata with one draw
+/// per stratum; the strata are then shuffled independently per parameter.
+/// Returns an `n`-by-`k` matrix indexed `[sample][parameter]`.
+pub fn latin_hypercube(n: usize, k: usize, rng: &mut Lcg) -> Vec<Vec<f64>> {
+    let mut columns: Vec<Vec<f64>> = Vec::with_capacity(k);
+    for _ in 0..k {
+        let mut col: Vec<f64> = (0..n)
+            .map(|s| (s as f64 + rng.next_f64()) / n as f64)
+            .collect();
+        rng.shuffle(&mut col);
+        columns.push(col);
+    }
+    (0..n)
+        .map(|i| (0..k).map(|j| columns[j][i]).collect())
+        .collect()
+}
+
+/// Draw `n` points of a Sobol low-discrepancy sequence in [0, 1) over `dim`
+/// dimensions (`dim` is capped at 6). Uses Joe–Kuo direction numbers with
+/// Gray-code (Antonov–Saleev) generation; the first point is the origin, as in
+/// the conventional Sobol definition. Returns an `n`-by-`dim` matrix indexed
+/// `[sample][dimension]`. Unlike [`latin_hypercube`] the sequence is
+/// deterministic and takes no RNG, so a given `n` always yields the same grid.
+pub fn sobol_sequence(n: usize, dim: usize) -> Vec<Vec<f64>> {
+    const BITS: usize = 32;
+    // Joe–Kuo primitive-polynomial data for dimensions 2..=6: (s, a, m[..s]).
+    // Dimension 1 is the plain radical inverse (identity direction numbers).
+    let poly: [(usize, u32, &[u32]); 5] = [
+        (1, 0, &[1]),
+        (2, 1, &[1, 3]),
+        (3, 1, &[1, 3, 1]),
+        (3, 2, &[1, 1, 1]),
+        (4, 1, &[1, 1, 3, 3]),
+    ];
+    let dim = dim.min(6).max(1);
+
+    // Direction numbers v[d][i], i = 1..=BITS.
+    let mut v = vec![vec![0u32; BITS + 1]; dim];
+    for i in 1..=BITS {
+        v[0][i] = 1u32 << (BITS - i); // dimension 1: identity
+    }
+    for d in 1..dim {
+        let (s, a, m) = poly[d - 1];
+        for i in 1..=BITS {
+            if i <= s {
+                v[d][i] = m[i - 1] << (BITS - i);
+            } else {
+                let mut val = v[d][i - s] ^ (v[d][i - s] >> s);
+                for j in 1..s {
+                    if (a >> (s - 1 - j)) & 1 == 1 {
+                        val ^= v[d][i - j];
+                    }
+                }
+                v[d][i] = val;
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(n);
+    if n == 0 {
+        return out;
+    }
+    out.push(vec![0.0; dim]); // first point is the origin
+    let mut x = vec![0u32; dim];
+    let scale = (1u64 << BITS) as f64;
+    for i in 1..n {
+        // Position (1-based) of the rightmost zero bit of (i-1).
+        let c = (i - 1).trailing_ones() as usize + 1;
+        for d in 0..dim {
+            x[d] ^= v[d][c];
+        }
+        out.push(x.iter().map(|&xi| xi as f64 / scale).collect());
+    }
+    out
+}
+
+/// Empirical-CDF summary of one output across converged samples.
+#[derive(Clone, Debug)]
+pub struct OutputStats {
+    pub mean: f64,
+    pub std: f64,
+    pub p5: f64,
+    pub p50: f64,
+    pub p95: f64,
+    /// Quantized CDF points `(value, cumulative_probability)`.
+    pub cdf: Vec<(f64, f64)>,
+}
+
+/// Percentile `q` in [0, 1] from sorted values using the `(rank+0.5)/M`
+/// plotting position with linear interpolation between bracketing samples.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let m = sorted.len();
+    if m == 0 {
+        return f64::NAN;
+    }
+    if m == 1 {
+        return sorted[0];
+    }
+    // Plotting positions p_i = (i + 0.5) / m for i = 0..m-1.
+    let pos = q * m as f64 - 0.5;
+    if pos <= 0.0 {
+        return sorted[0];
+    }
+    if pos >= (m - 1) as f64 {
+        return sorted[m - 1];
+    }
+    let lo = pos.floor() as usize;
+    let frac = pos - lo as f64;
+    sorted[lo] + frac * (sorted[lo + 1] - sorted[lo])
+}
+
+/// Build mean/std/percentiles and a quantized empirical CDF from raw values.
+pub fn summarize(values: &[f64], cdf_points: usize) -> OutputStats {
+    let m = values.len();
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mean = if m > 0 {
+        sorted.iter().sum::<f64>() / m as f64
+    } else {
+        f64::NAN
+    };
+    let var = if m > 1 {
+        sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (m - 1) as f64
+    } else {
+        0.0
+    };
+
+    let cdf = if m > 0 && cdf_points > 1 {
+        (0..cdf_points)
+            .map(|i| {
+                let q = i as f64 / (cdf_points - 1) as f64;
+                (percentile(&sorted, q), q)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    OutputStats {
+        mean,
+        std: var.sqrt(),
+        p5: percentile(&sorted, 0.05),
+        p50: percentile(&sorted, 0.50),
+        p95: percentile(&sorted, 0.95),
+        cdf,
+    }
+}
+
+/// Number of cycle parameters [`propagate_uncertainty`] propagates uncertainty
+/// through, matching [`crate::ffi::JacobianResult::param_names`]: mach, alt_km,
+/// bpr, opr, eta_comp, eta_turb.
+pub const UNCERTAINTY_N_PARAMS: usize = 6;
+
+/// Cholesky factor `L` (lower-triangular, `L · Lᵀ = cov`) of a symmetric
+/// positive-semidefinite 6x6 covariance matrix, via Cholesky-Banachiewicz.
+/// Used to draw correlated input perturbations for the Monte Carlo ensemble
+/// in [`propagate_uncertainty`]: `dx = L · z` for standard-normal `z` has
+/// covariance `cov`.
+fn cholesky(cov: &[[f64; UNCERTAINTY_N_PARAMS]; UNCERTAINTY_N_PARAMS]) -> [[f64; UNCERTAINTY_N_PARAMS]; UNCERTAINTY_N_PARAMS] {
+    let n = UNCERTAINTY_N_PARAMS;
+    let mut l = [[0.0; UNCERTAINTY_N_PARAMS]; UNCERTAINTY_N_PARAMS];
+    for i in 0..n {
+        for j in 0..=i {
+            let mut sum = cov[i][j];
+            for k in 0..j {
+                sum -= l[i][k] * l[j][k];
+            }
+            if i == j {
+                l[i][j] = sum.max(0.0).sqrt();
+            } else if l[j][j] > 0.0 {
+                l[i][j] = sum / l[j][j];
+            } else {
+                l[i][j] = 0.0;
+            }
+        }
+    }
+    l
+}
+
+/// Linear-propagation output covariance/std plus an optional Monte Carlo
+/// ensemble summary from [`propagate_uncertainty`].
+#[derive(Clone, Debug)]
+pub struct UncertaintyResult {
+    /// Nominal (unperturbed) output values: (tsfc, thrust, t4).
+    pub nominal: [f64; 3],
+    /// First-order propagated output covariance `Σ_y = Jᵀ Σ_x J` over
+    /// (tsfc, thrust, t4).
+    pub linear_cov: [[f64; 3]; 3],
+    /// `sqrt(diag(linear_cov))`, i.e. the linear-estimate output std devs.
+    pub linear_std: [f64; 3],
+    /// Number of Monte Carlo samples that actually converged (status 0);
+    /// 0 if no ensemble was requested or none converged.
+    pub mc_converged: usize,
+    /// Monte Carlo sample mean per output, or `None` if no ensemble ran.
+    pub mc_mean: Option<[f64; 3]>,
+    /// Monte Carlo sample std dev per output, or `None` if no ensemble ran.
+    pub mc_std: Option<[f64; 3]>,
+    /// Monte Carlo (p5, p50, p95) per output, or `None` if no ensemble ran.
+    pub mc_percentiles: Option<[(f64, f64, f64); 3]>,
+    /// True if any output's Monte Carlo std deviates from the linear estimate
+    /// by more than [`NONLINEARITY_REL_TOL`], meaning curvature beyond the
+    /// Jacobian's local linearization is significant at this operating point
+    /// and `linear_cov`/`linear_std` shouldn't be trusted alone.
+    pub nonlinear_warning: bool,
+    pub status: i32,
+}
+
+/// Relative tolerance between linear and Monte Carlo output std devs beyond
+/// which [`UncertaintyResult::nonlinear_warning`] is raised.
+pub const NONLINEARITY_REL_TOL: f64 = 0.25;
+
+/// Propagate input uncertainty `input_cov` (covariance over mach, alt_km,
+/// bpr, opr, eta_comp, eta_turb, in that order) through the cycle at
+/// `nominal`, around the fixed `t4_max`.
+///
+/// Always computes the first-order linear estimate `Σ_y = Jᵀ Σ_x J` from
+/// [`crate::ffi::compute_jacobian`]. When `mc_samples > 0`, also draws that
+/// many correlated perturbations (`dx = L·z`, `L` the Cholesky factor of
+/// `input_cov`, `z` standard normal via [`norm_ppf`] of [`Lcg`] draws),
+/// solves each perturbed point with [`crate::ffi::solve_batch`], and
+/// summarizes the converged outputs — exposing nonlinearity the linear
+/// estimate alone would miss.
+pub fn propagate_uncertainty(
+    nominal: crate::ffi::MinotaurInputExt,
+    t4_max: f64,
+    input_cov: [[f64; UNCERTAINTY_N_PARAMS]; UNCERTAINTY_N_PARAMS],
+    mc_samples: usize,
+    seed: u64,
+    threads: usize,
+) -> UncertaintyResult {
+    let jac = crate::ffi::compute_jacobian(
+        nominal.mach, nominal.alt_km, nominal.bpr, nominal.opr,
+        nominal.eta_comp, nominal.eta_turb, t4_max,
+    );
+
+    let mut linear_cov = [[0.0; 3]; 3];
+    for o1 in 0..3 {
+        for o2 in 0..3 {
+            let mut sum = 0.0;
+            for p1 in 0..UNCERTAINTY_N_PARAMS {
+                for p2 in 0..UNCERTAINTY_N_PARAMS {
+                    sum += jac.jacobian[p1][o1] * input_cov[p1][p2] * jac.jacobian[p2][o2];
+                }
+            }
+            linear_cov[o1][o2] = sum;
+        }
+    }
+    let linear_std = [
+        linear_cov[0][0].max(0.0).sqrt(),
+        linear_cov[1][1].max(0.0).sqrt(),
+        linear_cov[2][2].max(0.0).sqrt(),
+    ];
+
+    let mut result = UncertaintyResult {
+        nominal: [jac.base_tsfc, jac.base_thrust, jac.base_t4],
+        linear_cov,
+        linear_std,
+        mc_converged: 0,
+        mc_mean: None,
+        mc_std: None,
+        mc_percentiles: None,
+        nonlinear_warning: false,
+        status: jac.status,
+    };
+
+    if mc_samples == 0 || jac.status != 0 {
+        return result;
+    }
+
+    let l = cholesky(&input_cov);
+    let mut rng = Lcg::new(seed);
+    let perturbed: Vec<crate::ffi::MinotaurInputExt> = (0..mc_samples)
+        .map(|_| {
+            let z: [f64; UNCERTAINTY_N_PARAMS] =
+                std::array::from_fn(|_| norm_ppf(rng.next_f64()));
+            let mut dx = [0.0; UNCERTAINTY_N_PARAMS];
+            for i in 0..UNCERTAINTY_N_PARAMS {
+                for j in 0..=i {
+                    dx[i] += l[i][j] * z[j];
+                }
+            }
+            let mut inp = nominal;
+            inp.mach += dx[0];
+            inp.alt_km += dx[1];
+            inp.bpr += dx[2];
+            inp.opr += dx[3];
+            inp.eta_comp += dx[4];
+            inp.eta_turb += dx[5];
+            inp
+        })
+        .collect();
+
+    let outputs = crate::ffi::solve_batch(&perturbed, threads, crate::ffi::BATCH_BACKEND_CPU);
+    let converged: Vec<&crate::ffi::MinotaurOutput> = outputs.iter().filter(|o| o.status == 0).collect();
+    result.mc_converged = converged.len();
+
+    if !converged.is_empty() {
+        let tsfc: Vec<f64> = converged.iter().map(|o| o.tsfc_proxy).collect();
+        let thrust: Vec<f64> = converged.iter().map(|o| o.thrust_proxy).collect();
+        let t4: Vec<f64> = converged.iter().map(|o| o.t4).collect();
+
+        let tsfc_stats = summarize(&tsfc, 0);
+        let thrust_stats = summarize(&thrust, 0);
+        let t4_stats = summarize(&t4, 0);
+
+        result.mc_mean = Some([tsfc_stats.mean, thrust_stats.mean, t4_stats.mean]);
+        let mc_std = [tsfc_stats.std, thrust_stats.std, t4_stats.std];
+        result.mc_std = Some(mc_std);
+        result.mc_percentiles = Some([
+            (tsfc_stats.p5, tsfc_stats.p50, tsfc_stats.p95),
+            (thrust_stats.p5, thrust_stats.p50, thrust_stats.p95),
+            (t4_stats.p5, t4_stats.p50, t4_stats.p95),
+        ]);
+
+        result.nonlinear_warning = (0..3).any(|i| {
+            let rel = (mc_std[i] - linear_std[i]).abs() / linear_std[i].max(1e-9);
+            rel > NONLINEARITY_REL_TOL
+        });
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_norm_ppf_symmetry() {
+        assert!(norm_ppf(0.5).abs() < 1e-9);
+        assert!((norm_ppf(0.975) - 1.959_963_98).abs() < 1e-4);
+        assert!((norm_ppf(0.025) + 1.959_963_98).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_lhs_stratification() {
+        let mut rng = Lcg::new(7);
+        let n = 100;
+        let samples = latin_hypercube(n, 2, &mut rng);
+        assert_eq!(samples.len(), n);
+        // Exactly one point must fall in each stratum of each parameter.
+        for j in 0..2 {
+            let mut hit = vec![false; n];
+            for s in &samples {
+                let stratum = (s[j] * n as f64) as usize;
+                assert!(!hit[stratum], "stratum reused");
+                hit[stratum] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_in_unit_cube_and_deterministic() {
+        let a = sobol_sequence(64, 3);
+        let b = sobol_sequence(64, 3);
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, b, "Sobol sequence must be deterministic");
+        for p in &a {
+            assert_eq!(p.len(), 3);
+            for &c in p {
+                assert!((0.0..1.0).contains(&c), "point {} outside [0,1)", c);
+            }
+        }
+        // Each 1-D projection should fill its halves evenly for a power-of-two n.
+        for d in 0..3 {
+            let lower = a.iter().filter(|p| p[d] < 0.5).count();
+            assert_eq!(lower, 32, "dimension {} not balanced across the midpoint", d);
+        }
+    }
+
+    #[test]
+    fn test_percentile_ordering() {
+        let stats = summarize(&[1.0, 2.0, 3.0, 4.0, 5.0], 5);
+        assert!(stats.p5 <= stats.p50 && stats.p50 <= stats.p95);
+        assert!((stats.mean - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_reconstructs_covariance() {
+        let mut cov = [[0.0; UNCERTAINTY_N_PARAMS]; UNCERTAINTY_N_PARAMS];
+        for i in 0..UNCERTAINTY_N_PARAMS {
+            cov[i][i] = (i + 1) as f64 * 0.01;
+        }
+        cov[0][1] = 0.002;
+        cov[1][0] = 0.002;
+
+        let l = cholesky(&cov);
+        for i in 0..UNCERTAINTY_N_PARAMS {
+            for j in 0..UNCERTAINTY_N_PARAMS {
+                let mut sum = 0.0;
+                for k in 0..UNCERTAINTY_N_PARAMS {
+                    sum += l[i][k] * l[j][k];
+                }
+                assert!(
+                    (sum - cov[i][j]).abs() < 1e-9,
+                    "L*L^T[{},{}] = {} != cov = {}",
+                    i, j, sum, cov[i][j]
+                );
+            }
+        }
+    }
+}